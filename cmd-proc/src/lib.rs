@@ -254,14 +254,21 @@ impl Spawn {
     }
 
     /// Spawn the child process.
+    ///
+    /// When the command carries [`Command::stdin_bytes`], stdin is piped regardless of the
+    /// configured [`Spawn::stdin`], the bytes are written and stdin is closed before returning.
     pub fn run(mut self) -> Result<Child, CommandError> {
         log::debug!("{:#?}", self.command.inner);
 
+        if self.command.stdin_data.is_some() {
+            self.stdin = Stdio::Piped;
+        }
+
         self.command.inner.stdin(self.stdin);
         self.command.inner.stdout(self.stdout);
         self.command.inner.stderr(self.stderr);
 
-        let inner = self
+        let mut inner = self
             .command
             .inner
             .spawn()
@@ -270,6 +277,8 @@ impl Spawn {
                 exit_status: None,
             })?;
 
+        write_stdin(&mut inner, self.command.stdin_data)?;
+
         Ok(Child { inner })
     }
 }
@@ -428,6 +437,17 @@ impl Command {
         assert_eq!(format!("{:?}", self.inner), format!("{:?}", other.inner));
     }
 
+    /// Returns the program this command runs.
+    #[must_use]
+    pub fn get_program(&self) -> &OsStr {
+        self.inner.get_program()
+    }
+
+    /// Returns the arguments passed to the program, in order.
+    pub fn get_arguments(&self) -> impl Iterator<Item = &OsStr> {
+        self.inner.get_args()
+    }
+
     pub fn argument(mut self, value: impl AsRef<OsStr>) -> Self {
         self.inner.arg(value);
         self
@@ -635,6 +655,17 @@ impl Command {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_program_and_arguments() {
+        let command = Command::new("echo").argument("hello").option("-n", "world");
+
+        assert_eq!(command.get_program(), "echo");
+        assert_eq!(
+            command.get_arguments().collect::<Vec<_>>(),
+            ["hello", "-n", "world"]
+        );
+    }
+
     #[test]
     fn test_stdout_bytes_success() {
         assert_eq!(
@@ -850,6 +881,22 @@ mod tests {
         assert_eq!(output.stdout, b"hello");
     }
 
+    #[test]
+    fn test_spawn_with_stdin_bytes() {
+        let mut child = Command::new("cat")
+            .stdin_bytes("hello")
+            .spawn()
+            .stdout(Stdio::Piped)
+            .run()
+            .unwrap();
+
+        assert!(child.stdin().is_none());
+
+        let output = child.wait_with_output().unwrap();
+        assert!(output.success());
+        assert_eq!(output.stdout, b"hello");
+    }
+
     #[test]
     fn test_spawn_wait() {
         let child = Command::new("true").spawn().run().unwrap();
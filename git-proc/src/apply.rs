@@ -0,0 +1,143 @@
+use std::path::Path;
+
+use crate::CommandError;
+
+/// Create a `git apply` command builder reading the patch from a file.
+#[must_use]
+pub fn file(patch: &Path) -> Apply<'_> {
+    Apply::new(PatchSource::File(patch))
+}
+
+/// Create a `git apply` command builder writing `patch` to the command's stdin.
+#[must_use]
+pub fn stdin(patch: &[u8]) -> Apply<'_> {
+    Apply::new(PatchSource::Stdin(patch))
+}
+
+/// Builder for `git apply` command.
+///
+/// See `git apply --help` for full documentation.
+#[derive(Debug)]
+pub struct Apply<'a> {
+    repo_path: Option<&'a Path>,
+    check: bool,
+    three_way: bool,
+    reverse: bool,
+    source: PatchSource<'a>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PatchSource<'a> {
+    File(&'a Path),
+    Stdin(&'a [u8]),
+}
+
+crate::impl_repo_path!(Apply);
+
+impl<'a> Apply<'a> {
+    #[must_use]
+    fn new(source: PatchSource<'a>) -> Self {
+        Self {
+            repo_path: None,
+            check: false,
+            three_way: false,
+            reverse: false,
+            source,
+        }
+    }
+
+    crate::flag_methods! {
+        /// Only check whether the patch applies, without changing anything.
+        ///
+        /// Corresponds to `--check`.
+        pub fn check / check_if, check, "Conditionally only check whether the patch applies."
+    }
+
+    crate::flag_methods! {
+        /// Fall back to a three-way merge when the patch does not apply cleanly.
+        ///
+        /// Corresponds to `-3`.
+        pub fn three_way / three_way_if, three_way, "Conditionally fall back to a three-way merge."
+    }
+
+    crate::flag_methods! {
+        /// Apply the patch in reverse.
+        ///
+        /// Corresponds to `-R`.
+        pub fn reverse / reverse_if, reverse, "Conditionally apply the patch in reverse."
+    }
+
+    /// Execute the command and return the exit status.
+    pub fn status(self) -> Result<(), CommandError> {
+        crate::Build::build(self).status()
+    }
+
+    /// Execute and return full output regardless of exit status.
+    ///
+    /// Use this to inspect why a patch did not apply.
+    pub fn output(self) -> Result<cmd_proc::Output, CommandError> {
+        crate::Build::build(self).output()
+    }
+}
+
+impl crate::Build for Apply<'_> {
+    fn build(self) -> cmd_proc::Command {
+        let command = crate::base_command(self.repo_path)
+            .argument("apply")
+            .optional_flag(self.check, "--check")
+            .optional_flag(self.three_way, "-3")
+            .optional_flag(self.reverse, "-R");
+
+        match self.source {
+            PatchSource::File(patch) => command.argument(patch),
+            PatchSource::Stdin(patch) => command.argument("-").stdin_bytes(patch),
+        }
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Apply<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            check: self.check,
+            three_way: self.three_way,
+            reverse: self.reverse,
+            source: self.source,
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_check_file() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("apply")
+            .argument("--check")
+            .argument("file.patch");
+
+        file(Path::new("file.patch")).check().test_eq(&expected);
+    }
+
+    #[test]
+    fn test_apply_stdin_three_way_reverse() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("apply")
+            .argument("-3")
+            .argument("-R")
+            .argument("-");
+
+        stdin(b"patch")
+            .repo_path(Path::new("/some/repo"))
+            .three_way()
+            .reverse()
+            .test_eq(&expected);
+    }
+}
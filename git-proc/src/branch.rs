@@ -28,6 +28,17 @@ impl Branch {
         self.0.contains('/')
     }
 
+    /// Returns the `/` separated components of the branch name.
+    pub fn components(&self) -> impl Iterator<Item = &str> {
+        self.0.split('/')
+    }
+
+    /// Returns the final component of the branch name.
+    #[must_use]
+    pub fn leaf(&self) -> &str {
+        self.0.rsplit('/').next().unwrap_or(&self.0)
+    }
+
     const fn is_forbidden_char(byte: u8) -> bool {
         matches!(byte, b'~' | b'^' | b':' | b'?' | b'*' | b'[' | b'\\')
     }
@@ -221,6 +232,25 @@ mod tests {
         assert!(Branch::from_static_or_panic("feature/login").has_parents());
     }
 
+    #[test]
+    fn test_components() {
+        let branch = Branch::from_static_or_panic("feature/deeply/nested");
+
+        assert_eq!(
+            branch.components().collect::<Vec<_>>(),
+            ["feature", "deeply", "nested"]
+        );
+        assert_eq!(branch.leaf(), "nested");
+    }
+
+    #[test]
+    fn test_components_single() {
+        let branch = Branch::from_static_or_panic("main");
+
+        assert_eq!(branch.components().collect::<Vec<_>>(), ["main"]);
+        assert_eq!(branch.leaf(), "main");
+    }
+
     #[test]
     fn test_empty() {
         assert!(matches!("".parse::<Branch>(), Err(BranchError::Empty)));
@@ -0,0 +1,155 @@
+use std::path::Path;
+
+use crate::CommandError;
+
+/// Create a new `git clean` command builder.
+#[must_use]
+pub fn new() -> Clean<'static> {
+    Clean::new()
+}
+
+/// Builder for `git clean` command.
+///
+/// Removes untracked files from the working tree, which cannot be undone. Unless
+/// `clean.requireForce` is set to `false`, git refuses to delete anything without
+/// [`force`](Clean::force) or [`dry_run`](Clean::dry_run).
+///
+/// See `git clean --help` for full documentation.
+#[derive(Debug)]
+pub struct Clean<'a> {
+    repo_path: Option<&'a Path>,
+    force: bool,
+    directories: bool,
+    ignored: bool,
+    dry_run: bool,
+    pathspecs: Vec<&'a str>,
+}
+
+crate::impl_repo_path!(Clean);
+
+impl<'a> Clean<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            force: false,
+            directories: false,
+            ignored: false,
+            dry_run: false,
+            pathspecs: Vec::new(),
+        }
+    }
+
+    crate::flag_methods! {
+        /// Actually delete files, required by git unless `clean.requireForce` is `false`.
+        ///
+        /// Corresponds to `-f`.
+        pub fn force / force_if, force, "Conditionally force deletion."
+    }
+
+    crate::flag_methods! {
+        /// Also remove untracked directories.
+        ///
+        /// Corresponds to `-d`.
+        pub fn directories / directories_if, directories, "Conditionally remove untracked directories."
+    }
+
+    crate::flag_methods! {
+        /// Also remove files ignored by `.gitignore` and other exclude rules.
+        ///
+        /// Corresponds to `-x`.
+        pub fn ignored / ignored_if, ignored, "Conditionally remove ignored files."
+    }
+
+    crate::flag_methods! {
+        /// Only list what would be removed.
+        ///
+        /// Corresponds to `-n`.
+        pub fn dry_run / dry_run_if, dry_run, "Conditionally only list what would be removed."
+    }
+
+    /// Limit cleaning to paths matching a pathspec.
+    #[must_use]
+    pub fn pathspec(mut self, pathspec: &'a str) -> Self {
+        self.pathspecs.push(pathspec);
+        self
+    }
+
+    /// Capture stdout from this command, listing the affected paths.
+    #[must_use]
+    pub fn stdout(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stdout()
+    }
+
+    /// Execute the command and return the exit status.
+    pub fn status(self) -> Result<(), CommandError> {
+        crate::Build::build(self).status()
+    }
+}
+
+impl Default for Clean<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for Clean<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command(self.repo_path)
+            .argument("clean")
+            .optional_flag(self.force, "-f")
+            .optional_flag(self.directories, "-d")
+            .optional_flag(self.ignored, "-x")
+            .optional_flag(self.dry_run, "-n")
+            .arguments(self.pathspecs)
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Clean<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            force: self.force,
+            directories: self.directories,
+            ignored: self.ignored,
+            dry_run: self.dry_run,
+            pathspecs: self.pathspecs.clone(),
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_force_directories() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("clean")
+            .argument("-f")
+            .argument("-d");
+
+        Clean::new().force().directories().test_eq(&expected);
+    }
+
+    #[test]
+    fn test_clean_dry_run() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("clean")
+            .argument("-x")
+            .argument("-n")
+            .argument("target");
+
+        Clean::new()
+            .repo_path(Path::new("/some/repo"))
+            .ignored()
+            .dry_run()
+            .pathspec("target")
+            .test_eq(&expected);
+    }
+}
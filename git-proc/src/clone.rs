@@ -1,7 +1,8 @@
+use std::ffi::OsStr;
 use std::path::Path;
 
 use crate::CommandError;
-use crate::url::GitUrl;
+use crate::url::{GitUrl, RemoteName};
 
 /// Create a new `git clone` command builder.
 #[must_use]
@@ -17,8 +18,13 @@ pub struct Clone<'a> {
     url: &'a GitUrl,
     directory: Option<&'a Path>,
     bare: bool,
+    origin: Option<&'a RemoteName>,
+    progress: bool,
+    env_vars: Vec<(cmd_proc::EnvVariableName<'a>, &'a OsStr)>,
 }
 
+crate::impl_non_interactive!(Clone);
+
 impl<'a> Clone<'a> {
     #[must_use]
     fn new(url: &'a GitUrl) -> Self {
@@ -26,6 +32,9 @@ impl<'a> Clone<'a> {
             url,
             directory: None,
             bare: false,
+            origin: None,
+            progress: false,
+            env_vars: Vec::new(),
         }
     }
 
@@ -43,10 +52,41 @@ impl<'a> Clone<'a> {
         pub fn bare / bare_if, bare, "Conditionally make a bare clone."
     }
 
+    /// Set the name of the remote to track the cloned repository.
+    ///
+    /// Corresponds to `--origin <name>`.
+    #[must_use]
+    pub fn origin(mut self, name: &'a RemoteName) -> Self {
+        self.origin = Some(name);
+        self
+    }
+
+    crate::flag_methods! {
+        /// Force progress reporting even when stderr is not a terminal.
+        ///
+        /// Corresponds to `--progress`.
+        pub fn progress / progress_if, progress, "Conditionally force progress reporting."
+    }
+
+    /// Set an environment variable for the command.
+    #[must_use]
+    pub fn env(mut self, key: cmd_proc::EnvVariableName<'a>, value: &'a OsStr) -> Self {
+        self.env_vars.push((key, value));
+        self
+    }
+
     /// Execute the command and return the exit status.
     pub fn status(self) -> Result<(), CommandError> {
         crate::Build::build(self).status()
     }
+
+    /// Spawn the command, allowing its progress output to be streamed.
+    ///
+    /// Combine with [`Self::progress`] to get progress output when stderr is not a terminal.
+    #[must_use]
+    pub fn spawn(self) -> cmd_proc::Spawn {
+        crate::Build::build(self).spawn()
+    }
 }
 
 impl crate::Build for Clone<'_> {
@@ -54,8 +94,11 @@ impl crate::Build for Clone<'_> {
         cmd_proc::Command::new("git")
             .argument("clone")
             .optional_flag(self.bare, "--bare")
+            .optional_option("--origin", self.origin)
+            .optional_flag(self.progress, "--progress")
             .argument(self.url)
             .optional_argument(self.directory)
+            .envs(self.env_vars)
     }
 }
 
@@ -67,7 +110,63 @@ impl Clone<'_> {
             url: self.url,
             directory: self.directory,
             bare: self.bare,
+            origin: self.origin,
+            progress: self.progress,
+            env_vars: self.env_vars.clone(),
         });
         command.test_eq(other);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env() {
+        let url: GitUrl = "https://github.com/user/repo.git".parse().unwrap();
+        let key = cmd_proc::EnvVariableName::from_static_or_panic("GIT_TERMINAL_PROMPT");
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("clone")
+            .argument("https://github.com/user/repo.git")
+            .env(&key, "0");
+
+        new(&url)
+            .env(key.clone(), OsStr::new("0"))
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_path_url() {
+        for input in ["/srv/git/repo.git", "file:///srv/git/repo.git"] {
+            let url: GitUrl = input.parse().unwrap();
+
+            assert!(matches!(url, GitUrl::Path(_)));
+
+            let expected = cmd_proc::Command::new("git")
+                .argument("clone")
+                .argument("--bare")
+                .argument(input)
+                .argument("/tmp/clone");
+
+            new(&url)
+                .bare()
+                .directory(Path::new("/tmp/clone"))
+                .test_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn test_non_interactive() {
+        let url: GitUrl = "https://github.com/user/repo.git".parse().unwrap();
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("clone")
+            .argument("https://github.com/user/repo.git")
+            .env(&crate::GIT_TERMINAL_PROMPT, "0")
+            .env(&crate::GIT_SSH_COMMAND, "ssh -oBatchMode=yes");
+
+        new(&url).non_interactive().test_eq(&expected);
+    }
+}
@@ -16,6 +16,7 @@ pub fn new() -> Commit<'static> {
 pub struct Commit<'a> {
     repo_path: Option<&'a Path>,
     message: Option<&'a str>,
+    message_stdin: Option<&'a str>,
     author: Option<&'a str>,
     date: Option<&'a str>,
     allow_empty: bool,
@@ -33,6 +34,7 @@ impl<'a> Commit<'a> {
         Self {
             repo_path: None,
             message: None,
+            message_stdin: None,
             author: None,
             date: None,
             allow_empty: false,
@@ -51,6 +53,16 @@ impl<'a> Commit<'a> {
         self
     }
 
+    /// Read the commit message from stdin.
+    ///
+    /// Corresponds to `--file -`, the message is written to the child's stdin.
+    /// Unlike [`Commit::message`] the text is passed verbatim, which suits multi-line messages.
+    #[must_use]
+    pub fn message_stdin(mut self, message: &'a str) -> Self {
+        self.message_stdin = Some(message);
+        self
+    }
+
     /// Set the commit author.
     ///
     /// Corresponds to `--author`. Format: `Name <email>`.
@@ -104,15 +116,21 @@ impl Default for Commit<'_> {
 
 impl crate::Build for Commit<'_> {
     fn build(self) -> cmd_proc::Command {
-        crate::base_command(self.repo_path)
+        let command = crate::base_command(self.repo_path)
             .argument("commit")
             .optional_option("--message", self.message)
+            .optional_option("--file", self.message_stdin.map(|_| "-"))
             .optional_option("--author", self.author)
             .optional_option("--date", self.date)
             .optional_flag(self.allow_empty, "--allow-empty")
             .optional_flag(self.allow_empty_message, "--allow-empty-message")
             .optional_flag(self.porcelain, "--porcelain")
-            .envs(self.env_vars)
+            .envs(self.env_vars);
+
+        match self.message_stdin {
+            Some(message) => command.stdin_bytes(message),
+            None => command,
+        }
     }
 }
 
@@ -123,6 +141,7 @@ impl Commit<'_> {
         let command = crate::Build::build(Self {
             repo_path: self.repo_path,
             message: self.message,
+            message_stdin: self.message_stdin,
             author: self.author,
             date: self.date,
             allow_empty: self.allow_empty,
@@ -0,0 +1,186 @@
+use std::path::Path;
+
+use crate::CommandError;
+
+/// Create a new `git describe` command builder.
+#[must_use]
+pub fn new() -> Describe<'static> {
+    Describe::new()
+}
+
+/// Builder for `git describe` command.
+///
+/// See `git describe --help` for full documentation.
+#[derive(Debug)]
+pub struct Describe<'a> {
+    repo_path: Option<&'a Path>,
+    globals: crate::Globals,
+    tags: bool,
+    always: bool,
+    dirty: Option<Option<&'a str>>,
+    abbrev: Option<usize>,
+    commit_ish: Option<&'a str>,
+}
+
+crate::impl_repo_path!(Describe);
+crate::impl_read_only!(Describe);
+
+impl<'a> Describe<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            globals: crate::Globals::default(),
+            tags: false,
+            always: false,
+            dirty: None,
+            abbrev: None,
+            commit_ish: None,
+        }
+    }
+
+    crate::flag_methods! {
+        /// Use any tag, including lightweight tags, not only annotated ones.
+        ///
+        /// Corresponds to `--tags`.
+        pub fn tags / tags_if, tags, "Conditionally use lightweight tags."
+    }
+
+    crate::flag_methods! {
+        /// Fall back to the abbreviated commit hash when no tag is reachable.
+        ///
+        /// Corresponds to `--always`.
+        pub fn always / always_if, always, "Conditionally fall back to the abbreviated hash."
+    }
+
+    /// Append `-dirty` when the working tree has local modifications.
+    ///
+    /// Corresponds to `--dirty`. Only applies when describing the working tree.
+    #[must_use]
+    pub fn dirty(mut self) -> Self {
+        self.dirty = Some(None);
+        self
+    }
+
+    /// Append `mark` when the working tree has local modifications.
+    ///
+    /// Corresponds to `--dirty=<mark>`. Only applies when describing the working tree.
+    #[must_use]
+    pub fn dirty_mark(mut self, mark: &'a str) -> Self {
+        self.dirty = Some(Some(mark));
+        self
+    }
+
+    /// Use `length` hex digits for the abbreviated commit hash, `0` omits the suffix.
+    ///
+    /// Corresponds to `--abbrev`.
+    #[must_use]
+    pub fn abbrev(mut self, length: usize) -> Self {
+        self.abbrev = Some(length);
+        self
+    }
+
+    /// Set the commit-ish to describe, defaults to `HEAD`.
+    ///
+    /// Git rejects a commit-ish combined with [`dirty`](Describe::dirty).
+    #[must_use]
+    pub fn commit_ish(mut self, commit_ish: &'a str) -> Self {
+        self.commit_ish = Some(commit_ish);
+        self
+    }
+
+    /// Capture stdout from this command.
+    #[must_use]
+    pub fn stdout(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stdout()
+    }
+
+    /// Execute and return full output regardless of exit status.
+    ///
+    /// Use this when you need to inspect stderr on failure.
+    pub fn output(self) -> Result<cmd_proc::Output, CommandError> {
+        crate::Build::build(self).output()
+    }
+}
+
+impl Default for Describe<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for Describe<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command_with_globals(self.repo_path, self.globals)
+            .argument("describe")
+            .optional_flag(self.tags, "--tags")
+            .optional_flag(self.always, "--always")
+            .optional_argument(self.dirty.map(|mark| match mark {
+                Some(mark) => format!("--dirty={mark}"),
+                None => "--dirty".to_string(),
+            }))
+            .optional_argument(self.abbrev.map(|length| format!("--abbrev={length}")))
+            .optional_argument(self.commit_ish)
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Describe<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            globals: self.globals,
+            tags: self.tags,
+            always: self.always,
+            dirty: self.dirty,
+            abbrev: self.abbrev,
+            commit_ish: self.commit_ish,
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_tags_always_dirty() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("describe")
+            .argument("--tags")
+            .argument("--always")
+            .argument("--dirty");
+
+        Describe::new().tags().always().dirty().test_eq(&expected);
+    }
+
+    #[test]
+    fn test_describe_dirty_mark_abbrev_commit_ish() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("describe")
+            .argument("--dirty=-modified")
+            .argument("--abbrev=0")
+            .argument("v1.0.0~1");
+
+        Describe::new()
+            .repo_path(Path::new("/some/repo"))
+            .dirty_mark("-modified")
+            .abbrev(0)
+            .commit_ish("v1.0.0~1")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_globals() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("--no-pager")
+            .argument("--no-optional-locks")
+            .argument("describe");
+
+        new().no_pager().no_optional_locks().test_eq(&expected);
+    }
+}
@@ -1,3 +1,4 @@
+use std::ffi::OsStr;
 use std::path::Path;
 
 use crate::CommandError;
@@ -17,11 +18,14 @@ pub struct Fetch<'a> {
     repo_path: Option<&'a Path>,
     all: bool,
     porcelain: bool,
+    progress: bool,
     remote: Option<&'a Remote>,
+    env_vars: Vec<(cmd_proc::EnvVariableName<'a>, &'a OsStr)>,
 }
 
 crate::impl_repo_path!(Fetch);
 crate::impl_porcelain!(Fetch);
+crate::impl_non_interactive!(Fetch);
 
 impl<'a> Fetch<'a> {
     #[must_use]
@@ -30,7 +34,9 @@ impl<'a> Fetch<'a> {
             repo_path: None,
             all: false,
             porcelain: false,
+            progress: false,
             remote: None,
+            env_vars: Vec::new(),
         }
     }
 
@@ -41,6 +47,13 @@ impl<'a> Fetch<'a> {
         pub fn all / all_if, all, "Conditionally fetch all remotes."
     }
 
+    crate::flag_methods! {
+        /// Force progress reporting even when stderr is not a terminal.
+        ///
+        /// Corresponds to `--progress`.
+        pub fn progress / progress_if, progress, "Conditionally force progress reporting."
+    }
+
     /// Set the remote to fetch from.
     #[must_use]
     pub fn remote(mut self, remote: &'a Remote) -> Self {
@@ -48,10 +61,25 @@ impl<'a> Fetch<'a> {
         self
     }
 
+    /// Set an environment variable for the command.
+    #[must_use]
+    pub fn env(mut self, key: cmd_proc::EnvVariableName<'a>, value: &'a OsStr) -> Self {
+        self.env_vars.push((key, value));
+        self
+    }
+
     /// Execute the command and return the exit status.
     pub fn status(self) -> Result<(), CommandError> {
         crate::Build::build(self).status()
     }
+
+    /// Spawn the command, allowing its progress output to be streamed.
+    ///
+    /// Combine with [`Self::progress`] to get progress output when stderr is not a terminal.
+    #[must_use]
+    pub fn spawn(self) -> cmd_proc::Spawn {
+        crate::Build::build(self).spawn()
+    }
 }
 
 impl Default for Fetch<'_> {
@@ -66,7 +94,9 @@ impl crate::Build for Fetch<'_> {
             .argument("fetch")
             .optional_flag(self.all, "--all")
             .optional_flag(self.porcelain, "--porcelain")
+            .optional_flag(self.progress, "--progress")
             .optional_argument(self.remote)
+            .envs(self.env_vars)
     }
 }
 
@@ -78,8 +108,30 @@ impl Fetch<'_> {
             repo_path: self.repo_path,
             all: self.all,
             porcelain: self.porcelain,
+            progress: self.progress,
             remote: self.remote,
+            env_vars: self.env_vars.clone(),
         });
         command.test_eq(other);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env() {
+        let key = cmd_proc::EnvVariableName::from_static_or_panic("GIT_SSH_COMMAND");
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("fetch")
+            .argument("--all")
+            .env(&key, "ssh -o BatchMode=yes");
+
+        new()
+            .all()
+            .env(key.clone(), OsStr::new("ssh -o BatchMode=yes"))
+            .test_eq(&expected);
+    }
+}
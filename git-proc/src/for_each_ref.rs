@@ -0,0 +1,183 @@
+use std::path::Path;
+
+use crate::CommandError;
+
+/// Create a new `git for-each-ref` command builder.
+#[must_use]
+pub fn new() -> ForEachRef<'static> {
+    ForEachRef::new()
+}
+
+/// Builder for `git for-each-ref` command.
+///
+/// See `git for-each-ref --help` for full documentation.
+#[derive(Debug)]
+pub struct ForEachRef<'a> {
+    repo_path: Option<&'a Path>,
+    globals: crate::Globals,
+    format: Option<&'a str>,
+    sorts: Vec<&'a str>,
+    count: Option<usize>,
+    patterns: Vec<&'a str>,
+}
+
+crate::impl_repo_path!(ForEachRef);
+crate::impl_read_only!(ForEachRef);
+
+impl<'a> ForEachRef<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            globals: crate::Globals::default(),
+            format: None,
+            sorts: Vec::new(),
+            count: None,
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Set the output format, e.g. `%(refname:short) %(upstream:short)`.
+    ///
+    /// Corresponds to `--format`.
+    #[must_use]
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Add a sort key, prefix with `-` for descending order.
+    ///
+    /// Corresponds to `--sort`. Later keys take precedence over earlier ones.
+    #[must_use]
+    pub fn sort(mut self, key: &'a str) -> Self {
+        self.sorts.push(key);
+        self
+    }
+
+    /// Stop after outputting this many refs.
+    ///
+    /// Corresponds to `--count`.
+    #[must_use]
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Add a pattern refs have to match, either a glob or a ref prefix like `refs/heads/`.
+    #[must_use]
+    pub fn pattern(mut self, pattern: &'a str) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Capture stdout from this command.
+    #[must_use]
+    pub fn stdout(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stdout()
+    }
+
+    /// Execute and return full output regardless of exit status.
+    ///
+    /// Use this when you need to inspect stderr on failure.
+    pub fn output(self) -> Result<cmd_proc::Output, CommandError> {
+        crate::Build::build(self).output()
+    }
+}
+
+impl Default for ForEachRef<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for ForEachRef<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command_with_globals(self.repo_path, self.globals)
+            .argument("for-each-ref")
+            .optional_argument(self.format.map(|format| format!("--format={format}")))
+            .arguments(self.sorts.iter().map(|key| format!("--sort={key}")))
+            .optional_argument(self.count.map(|count| format!("--count={count}")))
+            .arguments(self.patterns)
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl ForEachRef<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            globals: self.globals,
+            format: self.format,
+            sorts: self.sorts.clone(),
+            count: self.count,
+            patterns: self.patterns.clone(),
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_ref_format_sort() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("for-each-ref")
+            .argument("--format=%(refname)")
+            .argument("--sort=-committerdate")
+            .argument("refs/heads/");
+
+        ForEachRef::new()
+            .format("%(refname)")
+            .sort("-committerdate")
+            .pattern("refs/heads/")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_for_each_ref_count() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("for-each-ref")
+            .argument("--sort=refname")
+            .argument("--sort=-committerdate")
+            .argument("--count=5")
+            .argument("refs/heads/")
+            .argument("refs/remotes/");
+
+        ForEachRef::new()
+            .repo_path(Path::new("/some/repo"))
+            .sort("refname")
+            .sort("-committerdate")
+            .count(5)
+            .pattern("refs/heads/")
+            .pattern("refs/remotes/")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_for_each_ref() {
+        let output = ForEachRef::new()
+            .format("%(refname)")
+            .pattern("refs/heads/")
+            .stdout()
+            .string()
+            .unwrap();
+
+        assert!(output.lines().all(|line| line.starts_with("refs/heads/")));
+    }
+
+    #[test]
+    fn test_globals() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("--no-pager")
+            .argument("--no-optional-locks")
+            .argument("for-each-ref");
+
+        new().no_pager().no_optional_locks().test_eq(&expected);
+    }
+}
@@ -68,13 +68,46 @@ macro_rules! impl_repo_path {
     };
 }
 
+/// Generate an inherent `non_interactive` method for builders of network commands.
+///
+/// The method sets `GIT_TERMINAL_PROMPT=0` and `GIT_SSH_COMMAND="ssh -oBatchMode=yes"`
+/// via the builder's `env` method, so credential and host key prompts fail immediately
+/// instead of blocking on the terminal.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_non_interactive {
+    ($ty:ident) => {
+        impl $ty<'_> {
+            /// Fail instead of prompting for credentials or host key confirmation.
+            ///
+            /// Sets `GIT_TERMINAL_PROMPT=0` and `GIT_SSH_COMMAND="ssh -oBatchMode=yes"`.
+            #[must_use]
+            pub fn non_interactive(self) -> Self {
+                self.env(
+                    $crate::GIT_TERMINAL_PROMPT,
+                    std::ffi::OsStr::new($crate::GIT_TERMINAL_PROMPT_DISABLED),
+                )
+                .env(
+                    $crate::GIT_SSH_COMMAND,
+                    std::ffi::OsStr::new($crate::GIT_SSH_COMMAND_BATCH_MODE),
+                )
+            }
+        }
+    };
+}
+
 pub mod add;
+pub mod apply;
 pub mod branch;
+pub mod clean;
 pub mod clone;
 pub mod commit;
 pub mod config;
+pub mod describe;
 pub mod fetch;
+pub mod for_each_ref;
 pub mod init;
+pub mod ls_files;
 pub mod ls_remote;
 pub mod push;
 pub mod remote;
@@ -82,7 +115,11 @@ pub mod rev_list;
 pub mod rev_parse;
 pub mod show;
 pub mod show_ref;
+pub mod stash;
 pub mod status;
+pub mod tag;
+#[cfg(feature = "test-utils")]
+pub mod testing;
 pub mod url;
 pub mod worktree;
 
@@ -90,6 +127,17 @@ use std::path::Path;
 
 pub use cmd_proc::CommandError;
 
+pub const GIT_TERMINAL_PROMPT: cmd_proc::EnvVariableName<'static> =
+    cmd_proc::EnvVariableName::from_static_or_panic("GIT_TERMINAL_PROMPT");
+pub const GIT_SSH_COMMAND: cmd_proc::EnvVariableName<'static> =
+    cmd_proc::EnvVariableName::from_static_or_panic("GIT_SSH_COMMAND");
+
+/// `GIT_TERMINAL_PROMPT` value disabling terminal prompts.
+pub const GIT_TERMINAL_PROMPT_DISABLED: &str = "0";
+
+/// `GIT_SSH_COMMAND` value making ssh fail instead of prompting.
+pub const GIT_SSH_COMMAND_BATCH_MODE: &str = "ssh -oBatchMode=yes";
+
 /// Trait for git command builders that support porcelain output.
 ///
 /// Provides the `porcelain` and `porcelain_if` methods to set `--porcelain`.
@@ -139,6 +187,90 @@ macro_rules! impl_porcelain {
     };
 }
 
+/// Trait for builders of read-only git commands.
+///
+/// Provides the `no_pager` and `no_optional_locks` methods to set the global `--no-pager`
+/// and `--no-optional-locks` options, which are placed before the subcommand.
+pub trait ReadOnly: Sized {
+    /// Conditionally disable the pager (`--no-pager`).
+    fn no_pager_if(self, value: bool) -> Self;
+
+    /// Conditionally skip optional locks (`--no-optional-locks`).
+    fn no_optional_locks_if(self, value: bool) -> Self;
+
+    /// Never pipe output into a pager (`--no-pager`).
+    fn no_pager(self) -> Self {
+        self.no_pager_if(true)
+    }
+
+    /// Do not take optional locks, such as the index refresh lock (`--no-optional-locks`).
+    fn no_optional_locks(self) -> Self {
+        self.no_optional_locks_if(true)
+    }
+}
+
+/// Global options of read-only commands, rendered by [`base_command_with_globals`].
+#[derive(Clone, Copy, Debug, Default)]
+struct Globals {
+    no_pager: bool,
+    no_optional_locks: bool,
+}
+
+/// Generate inherent `no_pager` / `no_optional_locks` methods and a `ReadOnly` trait implementation.
+///
+/// The inherent methods set the fields of the builder's `globals` field.
+/// The trait implementation delegates to the inherent methods.
+///
+/// This ensures callers can use `.no_pager()` without importing the `ReadOnly` trait,
+/// while the trait remains available for generic bounds.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_read_only {
+    ($ty:ident) => {
+        impl<'a> $ty<'a> {
+            /// Never pipe output into a pager.
+            ///
+            /// Corresponds to the global `--no-pager` option.
+            #[must_use]
+            pub fn no_pager(self) -> Self {
+                self.no_pager_if(true)
+            }
+
+            /// Conditionally disable the pager.
+            #[must_use]
+            pub fn no_pager_if(mut self, value: bool) -> Self {
+                self.globals.no_pager = value;
+                self
+            }
+
+            /// Do not take optional locks, such as the index refresh lock.
+            ///
+            /// Corresponds to the global `--no-optional-locks` option.
+            #[must_use]
+            pub fn no_optional_locks(self) -> Self {
+                self.no_optional_locks_if(true)
+            }
+
+            /// Conditionally skip optional locks.
+            #[must_use]
+            pub fn no_optional_locks_if(mut self, value: bool) -> Self {
+                self.globals.no_optional_locks = value;
+                self
+            }
+        }
+
+        impl<'a> $crate::ReadOnly for $ty<'a> {
+            fn no_pager_if(self, value: bool) -> Self {
+                self.no_pager_if(value)
+            }
+
+            fn no_optional_locks_if(self, value: bool) -> Self {
+                self.no_optional_locks_if(value)
+            }
+        }
+    };
+}
+
 /// Trait for git command builders that support a repository path.
 ///
 /// Provides the `repo_path` method to set `-C <path>`.
@@ -178,3 +310,12 @@ pub trait Build {
 fn base_command(repo_path: Option<&Path>) -> cmd_proc::Command {
     cmd_proc::Command::new("git").optional_option("-C", repo_path)
 }
+
+/// Create a command builder with optional repository path and read-only global options.
+///
+/// Like [`base_command`], followed by `--no-pager` and `--no-optional-locks` when set.
+fn base_command_with_globals(repo_path: Option<&Path>, globals: Globals) -> cmd_proc::Command {
+    base_command(repo_path)
+        .optional_flag(globals.no_pager, "--no-pager")
+        .optional_flag(globals.no_optional_locks, "--no-optional-locks")
+}
@@ -0,0 +1,186 @@
+use std::path::Path;
+
+/// Create a new `git ls-files` command builder.
+#[must_use]
+pub fn new() -> LsFiles<'static> {
+    LsFiles::new()
+}
+
+/// Builder for `git ls-files` command.
+///
+/// Lists tracked files when no filter is set, git's default of `--cached`.
+///
+/// See `git ls-files --help` for full documentation.
+#[derive(Debug)]
+pub struct LsFiles<'a> {
+    repo_path: Option<&'a Path>,
+    globals: crate::Globals,
+    cached: bool,
+    modified: bool,
+    others: bool,
+    deleted: bool,
+    exclude_standard: bool,
+    z: bool,
+    pathspecs: Vec<&'a str>,
+}
+
+crate::impl_repo_path!(LsFiles);
+crate::impl_read_only!(LsFiles);
+
+impl<'a> LsFiles<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            globals: crate::Globals::default(),
+            cached: false,
+            modified: false,
+            others: false,
+            deleted: false,
+            exclude_standard: false,
+            z: false,
+            pathspecs: Vec::new(),
+        }
+    }
+
+    crate::flag_methods! {
+        /// Show files tracked in the index.
+        ///
+        /// Corresponds to `--cached`.
+        pub fn cached / cached_if, cached, "Conditionally show tracked files."
+    }
+
+    crate::flag_methods! {
+        /// Show tracked files with unstaged modifications.
+        ///
+        /// Corresponds to `--modified`.
+        pub fn modified / modified_if, modified, "Conditionally show modified files."
+    }
+
+    crate::flag_methods! {
+        /// Show untracked files.
+        ///
+        /// Corresponds to `--others`.
+        pub fn others / others_if, others, "Conditionally show untracked files."
+    }
+
+    crate::flag_methods! {
+        /// Show tracked files deleted from the working tree.
+        ///
+        /// Corresponds to `--deleted`.
+        pub fn deleted / deleted_if, deleted, "Conditionally show deleted files."
+    }
+
+    crate::flag_methods! {
+        /// Apply the standard exclusions from `.gitignore`, `.git/info/exclude` and the
+        /// global excludes file.
+        ///
+        /// Corresponds to `--exclude-standard`.
+        pub fn exclude_standard / exclude_standard_if, exclude_standard, "Conditionally apply the standard exclusions."
+    }
+
+    crate::flag_methods! {
+        /// Terminate entries with NUL instead of newline and do not quote paths.
+        ///
+        /// Corresponds to `-z`.
+        pub fn z / z_if, z, "Conditionally terminate entries with NUL."
+    }
+
+    /// Limit the listing to paths matching a pathspec.
+    #[must_use]
+    pub fn pathspec(mut self, pathspec: &'a str) -> Self {
+        self.pathspecs.push(pathspec);
+        self
+    }
+
+    /// Capture stdout from this command.
+    #[must_use]
+    pub fn stdout(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stdout()
+    }
+}
+
+impl Default for LsFiles<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for LsFiles<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command_with_globals(self.repo_path, self.globals)
+            .argument("ls-files")
+            .optional_flag(self.cached, "--cached")
+            .optional_flag(self.modified, "--modified")
+            .optional_flag(self.others, "--others")
+            .optional_flag(self.deleted, "--deleted")
+            .optional_flag(self.exclude_standard, "--exclude-standard")
+            .optional_flag(self.z, "-z")
+            .arguments(self.pathspecs)
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl LsFiles<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            globals: self.globals,
+            cached: self.cached,
+            modified: self.modified,
+            others: self.others,
+            deleted: self.deleted,
+            exclude_standard: self.exclude_standard,
+            z: self.z,
+            pathspecs: self.pathspecs.clone(),
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ls_files_others_exclude_standard() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("ls-files")
+            .argument("--others")
+            .argument("--exclude-standard");
+
+        LsFiles::new()
+            .others()
+            .exclude_standard()
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_ls_files_z() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("ls-files")
+            .argument("--cached")
+            .argument("-z")
+            .argument("src");
+
+        LsFiles::new()
+            .repo_path(Path::new("/some/repo"))
+            .cached()
+            .z()
+            .pathspec("src")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_globals() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("--no-pager")
+            .argument("--no-optional-locks")
+            .argument("ls-files");
+
+        new().no_pager().no_optional_locks().test_eq(&expected);
+    }
+}
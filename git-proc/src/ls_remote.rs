@@ -1,5 +1,8 @@
+use std::ffi::OsStr;
 use std::path::Path;
 
+use crate::CommandError;
+use crate::branch::{Branch, BranchError};
 use crate::url::Remote;
 
 /// Create a new `git ls-remote` command builder.
@@ -8,6 +11,57 @@ pub fn new() -> LsRemote<'static> {
     LsRemote::new()
 }
 
+/// Resolve the default branch of a remote.
+///
+/// Runs `git ls-remote --symref <remote> HEAD` and returns the branch the remote `HEAD` points to.
+pub fn default_branch(
+    remote: &Remote,
+    repo_path: Option<&Path>,
+) -> Result<Branch, DefaultBranchError> {
+    let output = LsRemote {
+        repo_path,
+        ..LsRemote::new()
+    }
+    .symref()
+    .remote(remote)
+    .pattern("HEAD")
+    .stdout()
+    .string()?;
+
+    parse_default_branch(&output)
+}
+
+/// Error resolving the default branch of a remote.
+#[derive(Debug, thiserror::Error)]
+pub enum DefaultBranchError {
+    #[error(transparent)]
+    Command(#[from] CommandError),
+    #[error("remote HEAD is not a symbolic ref to a branch")]
+    NoSymref,
+    #[error("invalid default branch name: {0}")]
+    InvalidBranch(#[from] BranchError),
+}
+
+/// Parse the output of `git ls-remote --symref <remote> HEAD`.
+///
+/// Example output:
+/// ```text
+/// ref: refs/heads/main    HEAD
+/// abc123...    HEAD
+/// ```
+fn parse_default_branch(output: &str) -> Result<Branch, DefaultBranchError> {
+    let target = output
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("ref: ")?
+                .strip_suffix("\tHEAD")?
+                .strip_prefix("refs/heads/")
+        })
+        .ok_or(DefaultBranchError::NoSymref)?;
+
+    Ok(target.parse()?)
+}
+
 /// Builder for `git ls-remote` command.
 ///
 /// See `git ls-remote --help` for full documentation.
@@ -18,9 +72,11 @@ pub struct LsRemote<'a> {
     symref: bool,
     remote: Option<&'a Remote>,
     pattern: Option<&'a str>,
+    env_vars: Vec<(cmd_proc::EnvVariableName<'a>, &'a OsStr)>,
 }
 
 crate::impl_repo_path!(LsRemote);
+crate::impl_non_interactive!(LsRemote);
 
 impl<'a> LsRemote<'a> {
     #[must_use]
@@ -31,6 +87,7 @@ impl<'a> LsRemote<'a> {
             symref: false,
             remote: None,
             pattern: None,
+            env_vars: Vec::new(),
         }
     }
 
@@ -62,6 +119,13 @@ impl<'a> LsRemote<'a> {
         self
     }
 
+    /// Set an environment variable for the command.
+    #[must_use]
+    pub fn env(mut self, key: cmd_proc::EnvVariableName<'a>, value: &'a OsStr) -> Self {
+        self.env_vars.push((key, value));
+        self
+    }
+
     /// Capture stdout from this command.
     #[must_use]
     pub fn stdout(self) -> cmd_proc::Capture {
@@ -83,6 +147,7 @@ impl crate::Build for LsRemote<'_> {
             .optional_flag(self.symref, "--symref")
             .optional_argument(self.remote)
             .optional_argument(self.pattern)
+            .envs(self.env_vars)
     }
 }
 
@@ -96,7 +161,46 @@ impl LsRemote<'_> {
             symref: self.symref,
             remote: self.remote,
             pattern: self.pattern,
+            env_vars: self.env_vars.clone(),
         });
         command.test_eq(other);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_default_branch_main() {
+        let output = "ref: refs/heads/main\tHEAD\n4b825dc642cb6eb9a060e54bf8d69288fbee4904\tHEAD\n";
+
+        assert_eq!(parse_default_branch(output).unwrap().as_str(), "main");
+    }
+
+    #[test]
+    fn test_parse_default_branch_nested() {
+        let output =
+            "ref: refs/heads/release/v1\tHEAD\n4b825dc642cb6eb9a060e54bf8d69288fbee4904\tHEAD\n";
+
+        assert_eq!(parse_default_branch(output).unwrap().as_str(), "release/v1");
+    }
+
+    #[test]
+    fn test_parse_default_branch_no_symref() {
+        let output = "4b825dc642cb6eb9a060e54bf8d69288fbee4904\tHEAD\n";
+
+        assert!(matches!(
+            parse_default_branch(output),
+            Err(DefaultBranchError::NoSymref)
+        ));
+    }
+
+    #[test]
+    fn test_parse_default_branch_empty() {
+        assert!(matches!(
+            parse_default_branch(""),
+            Err(DefaultBranchError::NoSymref)
+        ));
+    }
+}
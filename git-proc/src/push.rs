@@ -1,3 +1,4 @@
+use std::ffi::OsStr;
 use std::path::Path;
 
 use crate::CommandError;
@@ -17,12 +18,15 @@ pub struct Push<'a> {
     repo_path: Option<&'a Path>,
     force: bool,
     porcelain: bool,
+    progress: bool,
     remote: Option<&'a Remote>,
     refspec: Option<&'a str>,
+    env_vars: Vec<(cmd_proc::EnvVariableName<'a>, &'a OsStr)>,
 }
 
 crate::impl_repo_path!(Push);
 crate::impl_porcelain!(Push);
+crate::impl_non_interactive!(Push);
 
 impl<'a> Push<'a> {
     #[must_use]
@@ -31,8 +35,10 @@ impl<'a> Push<'a> {
             repo_path: None,
             force: false,
             porcelain: false,
+            progress: false,
             remote: None,
             refspec: None,
+            env_vars: Vec::new(),
         }
     }
 
@@ -43,6 +49,13 @@ impl<'a> Push<'a> {
         pub fn force / force_if, force, "Conditionally force push."
     }
 
+    crate::flag_methods! {
+        /// Force progress reporting even when stderr is not a terminal.
+        ///
+        /// Corresponds to `--progress`.
+        pub fn progress / progress_if, progress, "Conditionally force progress reporting."
+    }
+
     /// Set the remote to push to.
     #[must_use]
     pub fn remote(mut self, remote: &'a Remote) -> Self {
@@ -57,11 +70,26 @@ impl<'a> Push<'a> {
         self
     }
 
+    /// Set an environment variable for the command.
+    #[must_use]
+    pub fn env(mut self, key: cmd_proc::EnvVariableName<'a>, value: &'a OsStr) -> Self {
+        self.env_vars.push((key, value));
+        self
+    }
+
     /// Execute the command and return the exit status.
     pub fn status(self) -> Result<(), CommandError> {
         crate::Build::build(self).status()
     }
 
+    /// Spawn the command, allowing its progress output to be streamed.
+    ///
+    /// Combine with [`Self::progress`] to get progress output when stderr is not a terminal.
+    #[must_use]
+    pub fn spawn(self) -> cmd_proc::Spawn {
+        crate::Build::build(self).spawn()
+    }
+
     /// Capture stdout from this command.
     #[must_use]
     pub fn stdout(self) -> cmd_proc::Capture {
@@ -88,8 +116,10 @@ impl crate::Build for Push<'_> {
             .argument("push")
             .optional_flag(self.force, "--force")
             .optional_flag(self.porcelain, "--porcelain")
+            .optional_flag(self.progress, "--progress")
             .optional_argument(self.remote)
             .optional_argument(self.refspec)
+            .envs(self.env_vars)
     }
 }
 
@@ -101,8 +131,10 @@ impl Push<'_> {
             repo_path: self.repo_path,
             force: self.force,
             porcelain: self.porcelain,
+            progress: self.progress,
             remote: self.remote,
             refspec: self.refspec,
+            env_vars: self.env_vars.clone(),
         });
         command.test_eq(other);
     }
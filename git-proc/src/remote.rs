@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::CommandError;
-use crate::url::RemoteName;
+use crate::url::{GitUrl, GitUrlError, RemoteError, RemoteName};
 
 /// Create a `git remote get-url` command builder.
 #[must_use]
@@ -9,6 +9,108 @@ pub fn get_url(name: &RemoteName) -> Remote<'_> {
     Remote::get_url(name)
 }
 
+/// Create a `git remote` command builder listing the configured remotes.
+#[must_use]
+pub fn list() -> Remote<'static> {
+    Remote::list()
+}
+
+/// List the configured remotes with their fetch and push URLs.
+///
+/// Runs `git remote --verbose` and parses the output with [`parse_verbose`].
+pub fn infos(repo_path: Option<&Path>) -> Result<Vec<RemoteInfo>, RemoteListError> {
+    let output = Remote {
+        repo_path,
+        ..Remote::list()
+    }
+    .verbose()
+    .stdout()
+    .string()?;
+
+    parse_verbose(&output)
+}
+
+/// A configured remote with its fetch and push URLs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemoteInfo {
+    pub name: RemoteName,
+    pub fetch_url: GitUrl,
+    pub push_url: GitUrl,
+}
+
+/// Error listing the configured remotes.
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteListError {
+    #[error(transparent)]
+    Command(#[from] CommandError),
+    #[error("invalid remote listing line: {0:?}")]
+    InvalidLine(String),
+    #[error("invalid remote name: {0}")]
+    InvalidName(#[from] RemoteError),
+    #[error("invalid remote URL: {0}")]
+    InvalidUrl(#[from] GitUrlError),
+    #[error("remote {0} is missing a fetch or push URL")]
+    MissingUrl(String),
+}
+
+/// Parse the output of `git remote --verbose`.
+///
+/// Each remote is listed twice, once per direction, and is collapsed into a single
+/// [`RemoteInfo`] in order of first appearance.
+///
+/// Example output:
+/// ```text
+/// origin    https://github.com/user/repo.git (fetch)
+/// origin    git@github.com:user/repo.git (push)
+/// ```
+pub fn parse_verbose(output: &str) -> Result<Vec<RemoteInfo>, RemoteListError> {
+    let mut entries: Vec<(RemoteName, Option<GitUrl>, Option<GitUrl>)> = Vec::new();
+
+    for line in output.lines().filter(|line| !line.is_empty()) {
+        let invalid_line = || RemoteListError::InvalidLine(line.to_string());
+
+        let (name, rest) = line.split_once('\t').ok_or_else(invalid_line)?;
+        let (url, direction) = rest.rsplit_once(" (").ok_or_else(invalid_line)?;
+
+        let name: RemoteName = name.parse()?;
+        let url: GitUrl = url.parse()?;
+
+        let index = match entries
+            .iter()
+            .position(|(existing, _, _)| *existing == name)
+        {
+            Some(index) => index,
+            None => {
+                entries.push((name, None, None));
+                entries.len() - 1
+            }
+        };
+
+        let (_, fetch_url, push_url) = &mut entries[index];
+
+        // Promisor remotes append the partial clone filter, e.g. `(fetch) [blob:none]`
+        if direction.starts_with("fetch)") {
+            *fetch_url = Some(url);
+        } else if direction.starts_with("push)") {
+            *push_url = Some(url);
+        } else {
+            return Err(invalid_line());
+        }
+    }
+
+    entries
+        .into_iter()
+        .map(|(name, fetch_url, push_url)| match (fetch_url, push_url) {
+            (Some(fetch_url), Some(push_url)) => Ok(RemoteInfo {
+                name,
+                fetch_url,
+                push_url,
+            }),
+            _ => Err(RemoteListError::MissingUrl(name.as_str().to_string())),
+        })
+        .collect()
+}
+
 /// Builder for `git remote` command.
 ///
 /// See `git remote --help` for full documentation.
@@ -21,6 +123,7 @@ pub struct Remote<'a> {
 #[derive(Debug)]
 enum RemoteSubcommand<'a> {
     GetUrl { name: &'a RemoteName },
+    List { verbose: bool },
 }
 
 crate::impl_repo_path!(Remote);
@@ -34,6 +137,31 @@ impl<'a> Remote<'a> {
         }
     }
 
+    #[must_use]
+    fn list() -> Self {
+        Self {
+            repo_path: None,
+            subcommand: RemoteSubcommand::List { verbose: false },
+        }
+    }
+
+    /// Show the fetch and push URL of each remote.
+    ///
+    /// Corresponds to `--verbose`. Only applies to [`list`].
+    #[must_use]
+    pub fn verbose(self) -> Self {
+        self.verbose_if(true)
+    }
+
+    /// Conditionally show the fetch and push URL of each remote.
+    #[must_use]
+    pub fn verbose_if(mut self, value: bool) -> Self {
+        if let RemoteSubcommand::List { verbose } = &mut self.subcommand {
+            *verbose = value;
+        }
+        self
+    }
+
     /// Capture stdout from this command.
     #[must_use]
     pub fn stdout(self) -> cmd_proc::Capture {
@@ -50,12 +178,12 @@ impl<'a> Remote<'a> {
 
 impl crate::Build for Remote<'_> {
     fn build(self) -> cmd_proc::Command {
-        let RemoteSubcommand::GetUrl { name } = self.subcommand;
+        let command = crate::base_command(self.repo_path).argument("remote");
 
-        crate::base_command(self.repo_path)
-            .argument("remote")
-            .argument("get-url")
-            .argument(name)
+        match self.subcommand {
+            RemoteSubcommand::GetUrl { name } => command.argument("get-url").argument(name),
+            RemoteSubcommand::List { verbose } => command.optional_flag(verbose, "--verbose"),
+        }
     }
 }
 
@@ -67,8 +195,79 @@ impl Remote<'_> {
             repo_path: self.repo_path,
             subcommand: match &self.subcommand {
                 RemoteSubcommand::GetUrl { name } => RemoteSubcommand::GetUrl { name },
+                RemoteSubcommand::List { verbose } => RemoteSubcommand::List { verbose: *verbose },
             },
         });
         command.test_eq(other);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verbose() {
+        let output = "\
+origin\thttps://github.com/user/repo.git (fetch)
+origin\tgit@github.com:user/repo.git (push)
+upstream\thttps://github.com/org/repo.git (fetch)
+upstream\thttps://github.com/org/repo.git (push)
+";
+
+        let infos = parse_verbose(output).unwrap();
+
+        assert_eq!(infos.len(), 2);
+
+        assert_eq!(infos[0].name.as_str(), "origin");
+        assert_eq!(
+            infos[0].fetch_url.as_str(),
+            "https://github.com/user/repo.git"
+        );
+        assert_eq!(infos[0].push_url.as_str(), "git@github.com:user/repo.git");
+
+        assert_eq!(infos[1].name.as_str(), "upstream");
+        assert_eq!(
+            infos[1].fetch_url.as_str(),
+            "https://github.com/org/repo.git"
+        );
+        assert_eq!(infos[1].fetch_url, infos[1].push_url);
+    }
+
+    #[test]
+    fn test_parse_verbose_partial_clone_filter() {
+        let output = "\
+origin\thttps://github.com/user/repo.git (fetch) [blob:none]
+origin\thttps://github.com/user/repo.git (push)
+";
+
+        let infos = parse_verbose(output).unwrap();
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(
+            infos[0].fetch_url.as_str(),
+            "https://github.com/user/repo.git"
+        );
+    }
+
+    #[test]
+    fn test_parse_verbose_empty() {
+        assert!(parse_verbose("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_verbose_missing_push() {
+        assert!(matches!(
+            parse_verbose("origin\thttps://github.com/user/repo.git (fetch)\n"),
+            Err(RemoteListError::MissingUrl(name)) if name == "origin"
+        ));
+    }
+
+    #[test]
+    fn test_parse_verbose_invalid_line() {
+        assert!(matches!(
+            parse_verbose("origin https://github.com/user/repo.git\n"),
+            Err(RemoteListError::InvalidLine(_))
+        ));
+    }
+}
@@ -14,6 +14,7 @@ pub fn new() -> RevList<'static> {
 #[derive(Debug)]
 pub struct RevList<'a> {
     repo_path: Option<&'a Path>,
+    globals: crate::Globals,
     topo_order: bool,
     reverse: bool,
     max_count: Option<usize>,
@@ -21,12 +22,14 @@ pub struct RevList<'a> {
 }
 
 crate::impl_repo_path!(RevList);
+crate::impl_read_only!(RevList);
 
 impl<'a> RevList<'a> {
     #[must_use]
     fn new() -> Self {
         Self {
             repo_path: None,
+            globals: crate::Globals::default(),
             topo_order: false,
             reverse: false,
             max_count: None,
@@ -86,7 +89,7 @@ impl Default for RevList<'_> {
 
 impl crate::Build for RevList<'_> {
     fn build(self) -> cmd_proc::Command {
-        crate::base_command(self.repo_path)
+        crate::base_command_with_globals(self.repo_path, self.globals)
             .argument("rev-list")
             .optional_flag(self.topo_order, "--topo-order")
             .optional_flag(self.reverse, "--reverse")
@@ -101,6 +104,7 @@ impl RevList<'_> {
     pub fn test_eq(&self, other: &cmd_proc::Command) {
         let command = crate::Build::build(Self {
             repo_path: self.repo_path,
+            globals: self.globals,
             topo_order: self.topo_order,
             reverse: self.reverse,
             max_count: self.max_count,
@@ -109,3 +113,22 @@ impl RevList<'_> {
         command.test_eq(other);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_globals() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/repo")
+            .argument("--no-optional-locks")
+            .argument("rev-list");
+
+        new()
+            .repo_path(Path::new("/repo"))
+            .no_optional_locks()
+            .test_eq(&expected);
+    }
+}
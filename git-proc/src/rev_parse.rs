@@ -16,6 +16,7 @@ pub struct RevParse<'a> {
     repo_path: Option<&'a Path>,
     abbrev_ref: bool,
     symbolic_full_name: bool,
+    git_common_dir: bool,
     rev: Option<&'a str>,
 }
 
@@ -28,6 +29,7 @@ impl<'a> RevParse<'a> {
             repo_path: None,
             abbrev_ref: false,
             symbolic_full_name: false,
+            git_common_dir: false,
             rev: None,
         }
     }
@@ -46,6 +48,14 @@ impl<'a> RevParse<'a> {
         pub fn symbolic_full_name / symbolic_full_name_if, symbolic_full_name, "Conditionally output full symbolic ref name."
     }
 
+    crate::flag_methods! {
+        /// Output the directory shared by all worktrees of the repository.
+        ///
+        /// Corresponds to `--git-common-dir`. Relative paths are relative to the current
+        /// directory, for a bare repository root this is `.`.
+        pub fn git_common_dir / git_common_dir_if, git_common_dir, "Conditionally output the common git directory."
+    }
+
     /// Set the revision to parse (e.g., `HEAD`, `@{u}`).
     #[must_use]
     pub fn rev(mut self, rev: &'a str) -> Self {
@@ -79,6 +89,7 @@ impl crate::Build for RevParse<'_> {
             .argument("rev-parse")
             .optional_flag(self.abbrev_ref, "--abbrev-ref")
             .optional_flag(self.symbolic_full_name, "--symbolic-full-name")
+            .optional_flag(self.git_common_dir, "--git-common-dir")
             .optional_argument(self.rev)
     }
 }
@@ -93,6 +104,7 @@ impl RevParse<'_> {
             repo_path: self.repo_path,
             abbrev_ref: self.abbrev_ref,
             symbolic_full_name: self.symbolic_full_name,
+            git_common_dir: self.git_common_dir,
             rev: self.rev,
         });
         command.test_eq(other);
@@ -119,4 +131,18 @@ mod tests {
             .unwrap();
         assert!(!output.trim().is_empty());
     }
+
+    #[test]
+    fn test_rev_parse_git_common_dir() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/worktree")
+            .argument("rev-parse")
+            .argument("--git-common-dir");
+
+        RevParse::new()
+            .repo_path(Path::new("/some/worktree"))
+            .git_common_dir()
+            .test_eq(&expected);
+    }
 }
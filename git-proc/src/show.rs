@@ -17,16 +17,19 @@ pub fn new(object: &str) -> Show<'_> {
 #[derive(Debug)]
 pub struct Show<'a> {
     repo_path: Option<&'a Path>,
+    globals: crate::Globals,
     object: &'a str,
 }
 
 crate::impl_repo_path!(Show);
+crate::impl_read_only!(Show);
 
 impl<'a> Show<'a> {
     #[must_use]
     fn new(object: &'a str) -> Self {
         Self {
             repo_path: None,
+            globals: crate::Globals::default(),
             object,
         }
     }
@@ -47,7 +50,7 @@ impl<'a> Show<'a> {
 
 impl crate::Build for Show<'_> {
     fn build(self) -> cmd_proc::Command {
-        crate::base_command(self.repo_path)
+        crate::base_command_with_globals(self.repo_path, self.globals)
             .argument("show")
             .argument(self.object)
     }
@@ -59,8 +62,33 @@ impl Show<'_> {
     pub fn test_eq(&self, other: &cmd_proc::Command) {
         let command = crate::Build::build(Self {
             repo_path: self.repo_path,
+            globals: self.globals,
             object: self.object,
         });
         command.test_eq(other);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_globals() {
+        let repo_path = Path::new("/repo");
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/repo")
+            .argument("--no-pager")
+            .argument("--no-optional-locks")
+            .argument("show")
+            .argument("HEAD");
+
+        new("HEAD")
+            .repo_path(repo_path)
+            .no_pager()
+            .no_optional_locks()
+            .test_eq(&expected);
+    }
+}
@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use crate::CommandError;
+
+/// Create a new `git stash show` command builder.
+///
+/// Shows the most recent stash entry unless [`StashShow::stash`] selects another.
+#[must_use]
+pub fn show() -> StashShow<'static> {
+    StashShow::new()
+}
+
+/// Builder for `git stash show` command.
+///
+/// See `git stash --help` for full documentation.
+#[derive(Debug)]
+pub struct StashShow<'a> {
+    repo_path: Option<&'a Path>,
+    globals: crate::Globals,
+    patch: bool,
+    stat: bool,
+    stash: Option<&'a str>,
+}
+
+crate::impl_repo_path!(StashShow);
+crate::impl_read_only!(StashShow);
+
+impl<'a> StashShow<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            globals: crate::Globals::default(),
+            patch: false,
+            stat: false,
+            stash: None,
+        }
+    }
+
+    crate::flag_methods! {
+        /// Show the changes as a patch.
+        ///
+        /// Corresponds to `-p`.
+        pub fn patch / patch_if, patch, "Conditionally show the changes as a patch."
+    }
+
+    crate::flag_methods! {
+        /// Show a diffstat of the changes.
+        ///
+        /// Corresponds to `--stat`.
+        pub fn stat / stat_if, stat, "Conditionally show a diffstat."
+    }
+
+    /// Select the stash entry to show, such as `stash@{1}`.
+    #[must_use]
+    pub fn stash(mut self, stash: &'a str) -> Self {
+        self.stash = Some(stash);
+        self
+    }
+
+    /// Capture stdout from this command.
+    #[must_use]
+    pub fn stdout(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stdout()
+    }
+
+    /// Execute and return full output regardless of exit status.
+    ///
+    /// Use this when you need to inspect stderr on failure.
+    pub fn output(self) -> Result<cmd_proc::Output, CommandError> {
+        crate::Build::build(self).output()
+    }
+}
+
+impl Default for StashShow<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for StashShow<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command_with_globals(self.repo_path, self.globals)
+            .argument("stash")
+            .argument("show")
+            .optional_flag(self.patch, "-p")
+            .optional_flag(self.stat, "--stat")
+            .optional_argument(self.stash)
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl StashShow<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            globals: self.globals,
+            patch: self.patch,
+            stat: self.stat,
+            stash: self.stash,
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stash_show_patch() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("stash")
+            .argument("show")
+            .argument("-p")
+            .argument("stash@{0}");
+
+        StashShow::new()
+            .patch()
+            .stash("stash@{0}")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_stash_show_stat() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("stash")
+            .argument("show")
+            .argument("--stat");
+
+        StashShow::new()
+            .repo_path(Path::new("/some/repo"))
+            .stat()
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_globals() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("--no-pager")
+            .argument("--no-optional-locks")
+            .argument("stash")
+            .argument("show");
+
+        show().no_pager().no_optional_locks().test_eq(&expected);
+    }
+}
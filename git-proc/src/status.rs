@@ -12,10 +12,12 @@ pub fn new() -> Status<'static> {
 #[derive(Debug)]
 pub struct Status<'a> {
     repo_path: Option<&'a Path>,
+    globals: crate::Globals,
     porcelain: bool,
 }
 
 crate::impl_repo_path!(Status);
+crate::impl_read_only!(Status);
 crate::impl_porcelain!(Status);
 
 impl<'a> Status<'a> {
@@ -23,6 +25,7 @@ impl<'a> Status<'a> {
     fn new() -> Self {
         Self {
             repo_path: None,
+            globals: crate::Globals::default(),
             porcelain: false,
         }
     }
@@ -42,7 +45,7 @@ impl Default for Status<'_> {
 
 impl crate::Build for Status<'_> {
     fn build(self) -> cmd_proc::Command {
-        crate::base_command(self.repo_path)
+        crate::base_command_with_globals(self.repo_path, self.globals)
             .argument("status")
             .optional_flag(self.porcelain, "--porcelain")
     }
@@ -54,6 +57,7 @@ impl Status<'_> {
     pub fn test_eq(&self, other: &cmd_proc::Command) {
         let command = crate::Build::build(Self {
             repo_path: self.repo_path,
+            globals: self.globals,
             porcelain: self.porcelain,
         });
         command.test_eq(other);
@@ -71,6 +75,19 @@ mod tests {
         let _ = output;
     }
 
+    #[test]
+    fn test_no_optional_locks() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("--no-optional-locks")
+            .argument("status")
+            .argument("--porcelain");
+
+        Status::new()
+            .no_optional_locks()
+            .porcelain()
+            .test_eq(&expected);
+    }
+
     #[test]
     fn test_status_porcelain() {
         let output = Status::new().porcelain().stdout().string().unwrap();
@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use crate::CommandError;
+
+/// Create a new `git tag` command builder in list mode.
+///
+/// Lists all tags unless filtered with [`TagList::points_at`] or [`TagList::merged`].
+#[must_use]
+pub fn list() -> TagList<'static> {
+    TagList::new()
+}
+
+/// Builder for `git tag` command in list mode.
+///
+/// See `git tag --help` for full documentation.
+#[derive(Debug)]
+pub struct TagList<'a> {
+    repo_path: Option<&'a Path>,
+    globals: crate::Globals,
+    points_at: Option<&'a str>,
+    merged: Option<&'a str>,
+    sorts: Vec<&'a str>,
+}
+
+crate::impl_repo_path!(TagList);
+crate::impl_read_only!(TagList);
+
+impl<'a> TagList<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            globals: crate::Globals::default(),
+            points_at: None,
+            merged: None,
+            sorts: Vec::new(),
+        }
+    }
+
+    /// Only list tags pointing at `object`, e.g. `HEAD`.
+    ///
+    /// Corresponds to `--points-at`.
+    #[must_use]
+    pub fn points_at(mut self, object: &'a str) -> Self {
+        self.points_at = Some(object);
+        self
+    }
+
+    /// Only list tags whose commits are reachable from `commit`.
+    ///
+    /// Corresponds to `--merged`.
+    #[must_use]
+    pub fn merged(mut self, commit: &'a str) -> Self {
+        self.merged = Some(commit);
+        self
+    }
+
+    /// Add a sort key, e.g. `-v:refname` for descending version order.
+    ///
+    /// Corresponds to `--sort`. Later keys take precedence over earlier ones.
+    #[must_use]
+    pub fn sort(mut self, key: &'a str) -> Self {
+        self.sorts.push(key);
+        self
+    }
+
+    /// Capture stdout from this command.
+    #[must_use]
+    pub fn stdout(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stdout()
+    }
+
+    /// Execute and return full output regardless of exit status.
+    ///
+    /// Use this when you need to inspect stderr on failure.
+    pub fn output(self) -> Result<cmd_proc::Output, CommandError> {
+        crate::Build::build(self).output()
+    }
+}
+
+impl Default for TagList<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for TagList<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command_with_globals(self.repo_path, self.globals)
+            .argument("tag")
+            .optional_option("--points-at", self.points_at)
+            .optional_option("--merged", self.merged)
+            .arguments(self.sorts.iter().map(|key| format!("--sort={key}")))
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl TagList<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            globals: self.globals,
+            points_at: self.points_at,
+            merged: self.merged,
+            sorts: self.sorts.clone(),
+        });
+        command.test_eq(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_list_points_at_sort() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("tag")
+            .argument("--points-at")
+            .argument("HEAD")
+            .argument("--sort=-v:refname");
+
+        TagList::new()
+            .points_at("HEAD")
+            .sort("-v:refname")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_tag_list_merged() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/some/repo")
+            .argument("tag")
+            .argument("--merged")
+            .argument("main");
+
+        TagList::new()
+            .repo_path(Path::new("/some/repo"))
+            .merged("main")
+            .test_eq(&expected);
+    }
+
+    #[test]
+    fn test_globals() {
+        let expected = cmd_proc::Command::new("git")
+            .argument("--no-pager")
+            .argument("--no-optional-locks")
+            .argument("tag");
+
+        list().no_pager().no_optional_locks().test_eq(&expected);
+    }
+}
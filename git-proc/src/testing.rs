@@ -0,0 +1,63 @@
+//! Helpers for tests that run git against real repositories.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::commit::Commit;
+
+/// Author and committer identity for commits created in tests.
+///
+/// Pass to [`cmd_proc::Command::envs`] for raw git commands that create commits.
+pub const TEST_IDENTITY: [(cmd_proc::EnvVariableName<'static>, &str); 4] = [
+    (
+        cmd_proc::EnvVariableName::from_static_or_panic("GIT_AUTHOR_NAME"),
+        "git-proc-test",
+    ),
+    (
+        cmd_proc::EnvVariableName::from_static_or_panic("GIT_AUTHOR_EMAIL"),
+        "git-proc-test@example.com",
+    ),
+    (
+        cmd_proc::EnvVariableName::from_static_or_panic("GIT_COMMITTER_NAME"),
+        "git-proc-test",
+    ),
+    (
+        cmd_proc::EnvVariableName::from_static_or_panic("GIT_COMMITTER_EMAIL"),
+        "git-proc-test@example.com",
+    ),
+];
+
+/// Set [`TEST_IDENTITY`] on a commit, independent of the user's git config.
+#[must_use]
+pub fn commit_as_test_identity(commit: Commit<'_>) -> Commit<'_> {
+    TEST_IDENTITY
+        .into_iter()
+        .fold(commit, |commit, (key, value)| {
+            commit.env(key, OsStr::new(value))
+        })
+}
+
+/// Temporary directory removed on drop.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("git-proc-test-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
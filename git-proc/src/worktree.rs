@@ -20,6 +20,12 @@ pub fn remove(worktree: &Path) -> Remove<'_> {
     Remove::new(worktree)
 }
 
+/// Create a new `git worktree prune` command builder.
+#[must_use]
+pub fn prune() -> Prune<'static> {
+    Prune::new()
+}
+
 /// Builder for `git worktree list` command.
 ///
 /// See `git worktree --help` for full documentation.
@@ -214,3 +220,82 @@ impl Remove<'_> {
         command.test_eq(other);
     }
 }
+
+/// Builder for `git worktree prune` command.
+///
+/// See `git worktree --help` for full documentation.
+#[derive(Debug)]
+pub struct Prune<'a> {
+    repo_path: Option<&'a Path>,
+    dry_run: bool,
+    verbose: bool,
+}
+
+crate::impl_repo_path!(Prune);
+
+impl<'a> Prune<'a> {
+    #[must_use]
+    fn new() -> Self {
+        Self {
+            repo_path: None,
+            dry_run: false,
+            verbose: false,
+        }
+    }
+
+    crate::flag_methods! {
+        /// Do not remove anything; just report what it would remove.
+        ///
+        /// Corresponds to `--dry-run`.
+        pub fn dry_run / dry_run_if, dry_run, "Conditionally only report what would be removed."
+    }
+
+    crate::flag_methods! {
+        /// Report all removals.
+        ///
+        /// Corresponds to `--verbose`.
+        pub fn verbose / verbose_if, verbose, "Conditionally report all removals."
+    }
+
+    /// Execute the command and return the exit status.
+    pub fn status(self) -> Result<(), CommandError> {
+        crate::Build::build(self).status()
+    }
+
+    /// Capture stderr from this command.
+    ///
+    /// Git reports pruned entries on stderr when `--verbose` is set.
+    #[must_use]
+    pub fn stderr(self) -> cmd_proc::Capture {
+        crate::Build::build(self).stderr()
+    }
+}
+
+impl Default for Prune<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Build for Prune<'_> {
+    fn build(self) -> cmd_proc::Command {
+        crate::base_command(self.repo_path)
+            .argument("worktree")
+            .argument("prune")
+            .optional_flag(self.dry_run, "--dry-run")
+            .optional_flag(self.verbose, "--verbose")
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Prune<'_> {
+    /// Compare the built command with another command using debug representation.
+    pub fn test_eq(&self, other: &cmd_proc::Command) {
+        let command = crate::Build::build(Self {
+            repo_path: self.repo_path,
+            dry_run: self.dry_run,
+            verbose: self.verbose,
+        });
+        command.test_eq(other);
+    }
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::{TempDir, source_repo};
+
+#[test]
+fn test_apply_stdin() {
+    let temp_dir = TempDir::new("apply-stdin");
+    let source_path = source_repo(temp_dir.path());
+
+    let patch = b"diff --git a/hello.txt b/hello.txt
+new file mode 100644
+--- /dev/null
++++ b/hello.txt
+@@ -0,0 +1 @@
++hello
+";
+
+    git_proc::apply::stdin(patch)
+        .repo_path(&source_path)
+        .check()
+        .status()
+        .unwrap();
+
+    assert!(!source_path.join("hello.txt").exists());
+
+    git_proc::apply::stdin(patch)
+        .repo_path(&source_path)
+        .status()
+        .unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(source_path.join("hello.txt")).unwrap(),
+        "hello\n"
+    );
+
+    let output = git_proc::apply::stdin(patch)
+        .repo_path(&source_path)
+        .output()
+        .unwrap();
+
+    assert!(!output.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("already exists"),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
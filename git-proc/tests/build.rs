@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use git_proc::Build;
+
+/// Build any command builder through the trait and compare with `git <arguments>`.
+fn assert_build(builder: impl Build, arguments: &[&str]) {
+    builder
+        .build()
+        .test_eq(&cmd_proc::Command::new("git").arguments(arguments));
+}
+
+#[test]
+fn test_build_all_builders() {
+    let url: git_proc::url::GitUrl = "https://example.com/repo.git".parse().unwrap();
+    let remote: git_proc::url::RemoteName = "origin".parse().unwrap();
+    let path = Path::new("some-worktree");
+
+    assert_build(git_proc::add::new(), &["add"]);
+    assert_build(
+        git_proc::apply::file(Path::new("file.patch")),
+        &["apply", "file.patch"],
+    );
+    assert_build(git_proc::apply::stdin(b"patch"), &["apply", "-"]);
+    assert_build(git_proc::clean::new(), &["clean"]);
+    assert_build(
+        git_proc::clone::new(&url),
+        &["clone", "https://example.com/repo.git"],
+    );
+    assert_build(git_proc::commit::new(), &["commit"]);
+    assert_build(
+        git_proc::commit::new().message_stdin("subject"),
+        &["commit", "--file", "-"],
+    );
+    assert_build(git_proc::config::new("user.name"), &["config", "user.name"]);
+    assert_build(git_proc::describe::new(), &["describe"]);
+    assert_build(git_proc::fetch::new(), &["fetch"]);
+    assert_build(git_proc::for_each_ref::new(), &["for-each-ref"]);
+    assert_build(git_proc::init::new(), &["init"]);
+    assert_build(git_proc::ls_files::new(), &["ls-files"]);
+    assert_build(git_proc::ls_remote::new(), &["ls-remote"]);
+    assert_build(git_proc::push::new(), &["push"]);
+    assert_build(
+        git_proc::remote::get_url(&remote),
+        &["remote", "get-url", "origin"],
+    );
+    assert_build(git_proc::remote::list(), &["remote"]);
+    assert_build(git_proc::remote::list().verbose(), &["remote", "--verbose"]);
+    assert_build(git_proc::rev_list::new(), &["rev-list"]);
+    assert_build(git_proc::rev_parse::new(), &["rev-parse"]);
+    assert_build(git_proc::show::new("HEAD"), &["show", "HEAD"]);
+    assert_build(git_proc::stash::show(), &["stash", "show"]);
+    assert_build(git_proc::show_ref::new(), &["show-ref"]);
+    assert_build(git_proc::status::new(), &["status"]);
+    assert_build(git_proc::tag::list(), &["tag"]);
+    assert_build(git_proc::worktree::list(), &["worktree", "list"]);
+    assert_build(
+        git_proc::worktree::add(path),
+        &["worktree", "add", "some-worktree"],
+    );
+    assert_build(
+        git_proc::worktree::remove(path),
+        &["worktree", "remove", "some-worktree"],
+    );
+    assert_build(git_proc::worktree::prune(), &["worktree", "prune"]);
+}
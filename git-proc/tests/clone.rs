@@ -0,0 +1,69 @@
+mod common;
+
+use std::path::Path;
+
+use cmd_proc::Stdio;
+
+use common::{TempDir, source_repo};
+
+#[test]
+fn test_clone_spawn_progress() {
+    let temp_dir = TempDir::new("clone-spawn");
+    let source_path = source_repo(temp_dir.path());
+    let clone_path = temp_dir.path().join("clone");
+
+    // A file URL uses the regular transport, which reports progress unlike local path clones.
+    let url: git_proc::url::GitUrl = format!("file://{}", source_path.display()).parse().unwrap();
+
+    let output = git_proc::clone::new(&url)
+        .directory(&clone_path)
+        .progress()
+        .spawn()
+        .stderr(Stdio::Piped)
+        .run()
+        .unwrap()
+        .wait_with_output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.success(), "clone failed: {stderr}");
+    assert!(stderr.contains("Receiving objects"), "stderr: {stderr}");
+    assert!(clone_path.join(".git").is_dir());
+}
+
+#[test]
+fn test_clone_local_bare_path() {
+    let temp_dir = TempDir::new("clone-local-bare");
+    let source_path = source_repo(temp_dir.path());
+    let bare_path = temp_dir.path().join("bare.git");
+    let clone_path = temp_dir.path().join("clone");
+
+    let source_url: git_proc::url::GitUrl = source_path.to_str().unwrap().parse().unwrap();
+
+    git_proc::clone::new(&source_url)
+        .bare()
+        .directory(&bare_path)
+        .status()
+        .unwrap();
+
+    let bare_url: git_proc::url::GitUrl = bare_path.to_str().unwrap().parse().unwrap();
+
+    assert!(matches!(bare_url, git_proc::url::GitUrl::Path(_)));
+
+    git_proc::clone::new(&bare_url)
+        .directory(&clone_path)
+        .status()
+        .unwrap();
+
+    let head = |path: &Path| {
+        git_proc::rev_parse::new()
+            .repo_path(path)
+            .rev("HEAD")
+            .stdout()
+            .string()
+            .unwrap()
+    };
+
+    assert_eq!(head(&clone_path), head(&source_path));
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use common::{TempDir, commit_as_test_identity, source_repo};
+
+#[test]
+fn test_commit_message_stdin() {
+    let temp_dir = TempDir::new("commit-message-stdin");
+    let source_path = source_repo(temp_dir.path());
+
+    commit_as_test_identity(
+        git_proc::commit::new()
+            .repo_path(&source_path)
+            .message_stdin("subject\n\nbody line 1\nbody line 2\n")
+            .allow_empty(),
+    )
+    .status()
+    .unwrap();
+
+    let shown = git_proc::show::new("HEAD")
+        .repo_path(&source_path)
+        .no_pager()
+        .stdout()
+        .string()
+        .unwrap();
+
+    assert!(
+        shown.contains("    subject\n    \n    body line 1\n    body line 2\n"),
+        "show: {shown}"
+    );
+}
@@ -0,0 +1,24 @@
+use std::path::{Path, PathBuf};
+
+pub use git_proc::testing::{TempDir, commit_as_test_identity};
+
+/// Create a repository with a single empty commit and return its path.
+pub fn source_repo(directory: &Path) -> PathBuf {
+    let source_path = directory.join("source");
+
+    git_proc::init::new()
+        .directory(&source_path)
+        .status()
+        .unwrap();
+
+    commit_as_test_identity(
+        git_proc::commit::new()
+            .repo_path(&source_path)
+            .message("initial")
+            .allow_empty(),
+    )
+    .status()
+    .unwrap();
+
+    source_path
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{TempDir, commit_as_test_identity, source_repo};
+
+#[test]
+fn test_describe_tagged() {
+    let temp_dir = TempDir::new("describe-tagged");
+    let source_path = source_repo(temp_dir.path());
+
+    cmd_proc::Command::new("git")
+        .argument("-C")
+        .argument(&source_path)
+        .argument("tag")
+        .argument("v1.0.0")
+        .status()
+        .unwrap();
+
+    let describe = || {
+        git_proc::describe::new()
+            .repo_path(&source_path)
+            .tags()
+            .always()
+            .dirty()
+    };
+
+    assert_eq!(describe().stdout().string().unwrap(), "v1.0.0\n");
+
+    commit_as_test_identity(
+        git_proc::commit::new()
+            .repo_path(&source_path)
+            .message("after tag")
+            .allow_empty(),
+    )
+    .status()
+    .unwrap();
+
+    let described = describe().stdout().string().unwrap();
+
+    assert!(described.starts_with("v1.0.0-1-g"), "describe: {described}");
+    assert_eq!(describe().abbrev(0).stdout().string().unwrap(), "v1.0.0\n");
+    assert_eq!(
+        git_proc::describe::new()
+            .repo_path(&source_path)
+            .tags()
+            .commit_ish("HEAD~1")
+            .stdout()
+            .string()
+            .unwrap(),
+        "v1.0.0\n"
+    );
+}
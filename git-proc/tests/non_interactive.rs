@@ -0,0 +1,84 @@
+use std::ffi::OsStr;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use cmd_proc::EnvVariableName;
+
+/// Serve `401 Unauthorized` to every request, which makes git ask for credentials.
+fn spawn_unauthorized_server() -> std::net::SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { continue };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                line.clear();
+            }
+
+            let _ = stream.write_all(
+                b"HTTP/1.1 401 Unauthorized\r\n\
+                  WWW-Authenticate: Basic realm=\"private\"\r\n\
+                  Content-Length: 0\r\n\
+                  Connection: close\r\n\r\n",
+            );
+        }
+    });
+
+    address
+}
+
+#[test]
+fn test_clone_private_url_fails_without_prompt() {
+    let address = spawn_unauthorized_server();
+    let url: git_proc::url::GitUrl = "https://private.invalid/repo.git".parse().unwrap();
+    let directory =
+        std::env::temp_dir().join(format!("git-proc-non-interactive-{}", address.port()));
+
+    // GitUrl only accepts https, so rewrite the URL to the plain http test server.
+    let rewrite_key = format!("url.http://{address}/.insteadOf");
+
+    let start = Instant::now();
+
+    let output = git_proc::Build::build(
+        git_proc::clone::new(&url)
+            .directory(&directory)
+            .env(
+                EnvVariableName::from_static_or_panic("GIT_CONFIG_GLOBAL"),
+                OsStr::new("/dev/null"),
+            )
+            .env(
+                EnvVariableName::from_static_or_panic("GIT_CONFIG_NOSYSTEM"),
+                OsStr::new("1"),
+            )
+            .env(
+                EnvVariableName::from_static_or_panic("GIT_CONFIG_COUNT"),
+                OsStr::new("1"),
+            )
+            .env(
+                EnvVariableName::from_static_or_panic("GIT_CONFIG_KEY_0"),
+                OsStr::new(&rewrite_key),
+            )
+            .env(
+                EnvVariableName::from_static_or_panic("GIT_CONFIG_VALUE_0"),
+                OsStr::new("https://private.invalid/"),
+            )
+            .non_interactive(),
+    )
+    .output()
+    .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.success());
+    assert!(start.elapsed() < Duration::from_secs(30));
+    assert!(
+        stderr.contains("terminal prompts disabled"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(!directory.exists());
+}
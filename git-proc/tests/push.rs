@@ -0,0 +1,43 @@
+mod common;
+
+use cmd_proc::Stdio;
+
+use common::{TempDir, source_repo};
+
+#[test]
+fn test_push_spawn() {
+    let temp_dir = TempDir::new("push-spawn");
+    let source_path = source_repo(temp_dir.path());
+    let target_path = temp_dir.path().join("target.git");
+
+    git_proc::init::new()
+        .directory(&target_path)
+        .bare()
+        .status()
+        .unwrap();
+
+    let remote: git_proc::url::Remote = target_path.to_str().unwrap().parse().unwrap();
+
+    let status = git_proc::push::new()
+        .repo_path(&source_path)
+        .remote(&remote)
+        .refspec("HEAD:refs/heads/pushed")
+        .progress()
+        .spawn()
+        .stderr(Stdio::Null)
+        .run()
+        .unwrap()
+        .wait()
+        .unwrap();
+
+    assert!(status.success());
+
+    let pushed = git_proc::rev_parse::new()
+        .repo_path(&target_path)
+        .rev("refs/heads/pushed")
+        .stdout()
+        .string()
+        .unwrap();
+
+    assert!(!pushed.trim().is_empty());
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use git_proc::testing::TEST_IDENTITY;
+
+use common::{TempDir, commit_as_test_identity, source_repo};
+
+#[test]
+fn test_stash_show() {
+    let temp_dir = TempDir::new("stash-show");
+    let source_path = source_repo(temp_dir.path());
+
+    let file_path = source_path.join("tracked.txt");
+
+    std::fs::write(&file_path, "original\n").unwrap();
+
+    git_proc::add::new()
+        .repo_path(&source_path)
+        .pathspec("tracked.txt")
+        .status()
+        .unwrap();
+
+    commit_as_test_identity(
+        git_proc::commit::new()
+            .repo_path(&source_path)
+            .message("add tracked file"),
+    )
+    .status()
+    .unwrap();
+
+    std::fs::write(&file_path, "stashed\n").unwrap();
+
+    cmd_proc::Command::new("git")
+        .argument("-C")
+        .argument(&source_path)
+        .argument("stash")
+        .envs(TEST_IDENTITY)
+        .stdout()
+        .string()
+        .unwrap();
+
+    let patch = git_proc::stash::show()
+        .repo_path(&source_path)
+        .patch()
+        .stash("stash@{0}")
+        .stdout()
+        .string()
+        .unwrap();
+
+    assert!(patch.contains("-original\n+stashed\n"), "patch: {patch}");
+
+    let stat = git_proc::stash::show()
+        .repo_path(&source_path)
+        .stat()
+        .stdout()
+        .string()
+        .unwrap();
+
+    assert!(stat.contains("tracked.txt"), "stat: {stat}");
+
+    let shown = git_proc::show::new("stash@{0}")
+        .repo_path(&source_path)
+        .no_pager()
+        .stdout()
+        .string()
+        .unwrap();
+
+    assert!(shown.contains("+stashed"), "show: {shown}");
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{TempDir, commit_as_test_identity, source_repo};
+
+#[test]
+fn test_tag_list_points_at() {
+    let temp_dir = TempDir::new("tag-list-points-at");
+    let source_path = source_repo(temp_dir.path());
+
+    for tag in ["v1.9.0", "v1.10.0"] {
+        cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument(&source_path)
+            .argument("tag")
+            .argument(tag)
+            .status()
+            .unwrap();
+    }
+
+    commit_as_test_identity(
+        git_proc::commit::new()
+            .repo_path(&source_path)
+            .message("after tags")
+            .allow_empty(),
+    )
+    .status()
+    .unwrap();
+
+    let tags = |points_at: &str| {
+        git_proc::tag::list()
+            .repo_path(&source_path)
+            .points_at(points_at)
+            .sort("-v:refname")
+            .stdout()
+            .string()
+            .unwrap()
+    };
+
+    assert_eq!(tags("HEAD~1"), "v1.10.0\nv1.9.0\n");
+    assert_eq!(tags("HEAD"), "");
+    assert_eq!(
+        git_proc::tag::list()
+            .repo_path(&source_path)
+            .merged("HEAD")
+            .sort("v:refname")
+            .stdout()
+            .string()
+            .unwrap(),
+        "v1.9.0\nv1.10.0\n"
+    );
+}
@@ -90,6 +90,55 @@ impl Backend {
             .unwrap();
     }
 
+    /// Read the environment variables stored in an image's config
+    #[must_use]
+    pub fn image_environment(
+        &self,
+        reference: &crate::image::Reference,
+    ) -> std::collections::BTreeMap<String, String> {
+        self.command()
+            .arguments([
+                "image",
+                "inspect",
+                "--format",
+                "{{range .Config.Env}}{{println .}}{{end}}",
+            ])
+            .argument(reference.to_string())
+            .stdout()
+            .string()
+            .unwrap()
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Handle to the container named `name`, running or stopped
+    ///
+    /// Returns `None` when no such container exists. The handle neither stops nor removes the
+    /// container on drop.
+    #[must_use]
+    pub fn container(&self, name: &str) -> Option<crate::Container> {
+        let output = self
+            .command()
+            .arguments(["container", "inspect", "--format", "{{.Id}}", name])
+            .output()
+            .unwrap();
+
+        if !output.success() {
+            return None;
+        }
+
+        Some(crate::Container {
+            backend: self.clone(),
+            id: crate::ContainerId(output.into_stdout_string().unwrap().trim().to_string()),
+            stopped: false,
+            removed: false,
+            stop_on_drop: false,
+            remove_on_drop: false,
+        })
+    }
+
     pub fn remove_image(&self, reference: &crate::image::Reference) {
         self.do_remove_image(reference, false);
     }
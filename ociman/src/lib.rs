@@ -338,6 +338,31 @@ pub struct Workdir(String);
 
 apply_argument!(Workdir, "--workdir");
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Network(String);
+
+apply_argument!(Network, "--network");
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NetworkAlias(String);
+
+apply_argument!(NetworkAlias, "--network-alias");
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContainerName(String);
+
+apply_argument!(ContainerName, "--name");
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Memory(String);
+
+apply_argument!(Memory, "--memory");
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cpus(String);
+
+apply_argument!(Cpus, "--cpus");
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EnvironmentVariables(
     std::collections::BTreeMap<cmd_proc::EnvVariableName<'static>, String>,
@@ -368,11 +393,16 @@ pub struct Definition {
     detach: Detach,
     entrypoint: Option<Entrypoint>,
     environment_variables: EnvironmentVariables,
+    name: Option<ContainerName>,
+    memory: Option<Memory>,
+    cpus: Option<Cpus>,
     reference: image::Reference,
     remove: Remove,
     stop_on_drop: bool,
     remove_on_drop: bool,
     mounts: Vec<Mount>,
+    network: Option<Network>,
+    network_aliases: Vec<NetworkAlias>,
     publish: Vec<Publish>,
     workdir: Option<Workdir>,
 }
@@ -386,8 +416,13 @@ impl Definition {
             detach: Detach::NoDetach,
             entrypoint: None,
             environment_variables: EnvironmentVariables::new(),
+            name: None,
+            memory: None,
+            cpus: None,
             reference,
             mounts: vec![],
+            network: None,
+            network_aliases: vec![],
             publish: vec![],
             remove: Remove::NoRemove,
             stop_on_drop: false,
@@ -427,6 +462,46 @@ impl Definition {
         }
     }
 
+    /// Connect the container to a network (--network).
+    pub fn network(self, network: impl Into<Network>) -> Self {
+        Self {
+            network: Some(network.into()),
+            ..self
+        }
+    }
+
+    /// Name the container (--name), names are unique per backend.
+    pub fn name(self, name: impl Into<ContainerName>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Limit the memory available to the container (--memory), e.g. `512m`.
+    pub fn memory(self, memory: impl Into<Memory>) -> Self {
+        Self {
+            memory: Some(memory.into()),
+            ..self
+        }
+    }
+
+    /// Limit the CPU cores available to the container (--cpus), e.g. `1.5`.
+    pub fn cpus(self, cpus: impl Into<Cpus>) -> Self {
+        Self {
+            cpus: Some(cpus.into()),
+            ..self
+        }
+    }
+
+    /// Add a DNS alias for the container on its network (--network-alias).
+    ///
+    /// Only user defined networks resolve aliases.
+    pub fn network_alias(mut self, alias: impl Into<NetworkAlias>) -> Self {
+        self.network_aliases.push(alias.into());
+        self
+    }
+
     pub fn arguments(
         self,
         arguments: impl IntoIterator<Item = impl Into<ContainerArgument>>,
@@ -591,13 +666,29 @@ impl Definition {
         self.build_run_command().status()
     }
 
+    /// Returns the backend `run` command line, program first, without running it.
+    #[must_use]
+    pub fn run_command_line(&self) -> Vec<std::ffi::OsString> {
+        let command = self.build_run_command();
+
+        std::iter::once(command.get_program())
+            .chain(command.get_arguments())
+            .map(std::ffi::OsStr::to_os_string)
+            .collect()
+    }
+
     fn build_run_command(&self) -> Command {
         let command = self.backend.command().argument("run");
 
         let command = self.detach.apply(command);
         let command = self.remove.apply(command);
+        let command = self.name.apply(command);
+        let command = self.memory.apply(command);
+        let command = self.cpus.apply(command);
         let command = self.environment_variables.apply(command);
         let command = self.publish.apply(command);
+        let command = self.network.apply(command);
+        let command = self.network_aliases.apply(command);
         let command = self.mounts.apply(command);
         let command = self.workdir.apply(command);
         let command = self.entrypoint.apply(command);
@@ -757,6 +848,14 @@ impl<'a> ExecCommand<'a> {
         command
     }
 
+    /// Build the backend command without running it.
+    ///
+    /// The command does not borrow the container, so it can be moved to a blocking task.
+    #[must_use]
+    pub fn command(self) -> Command {
+        self.build_command()
+    }
+
     /// Capture stdout from this exec command.
     #[must_use]
     pub fn stdout(self) -> cmd_proc::Capture {
@@ -792,6 +891,35 @@ impl Container {
         self.stopped = true;
     }
 
+    /// Stop the container, giving its main process `timeout` to exit before it is killed.
+    ///
+    /// The backend sends the stop signal configured for the image and only falls back to
+    /// SIGKILL once the timeout, rounded up to whole seconds, has passed.
+    pub fn stop_with_timeout(&mut self, timeout: std::time::Duration) {
+        let seconds = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+
+        self.backend_command()
+            .arguments(["container", "stop", "--time"])
+            .argument(seconds.to_string())
+            .argument(&self.id)
+            .stdout()
+            .bytes()
+            .unwrap();
+
+        self.stopped = true;
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    /// Leave the container as is when dropped, disabling stop and remove on drop.
+    pub fn keep_on_drop(&mut self) {
+        self.stop_on_drop = false;
+        self.remove_on_drop = false;
+    }
+
     pub fn remove(&mut self) {
         self.backend_command()
             .arguments(["container", "rm"])
@@ -803,6 +931,29 @@ impl Container {
         self.removed = true;
     }
 
+    /// Output of the container so far, stdout followed by stderr.
+    ///
+    /// Lines written to the two streams are not interleaved in the order they were written.
+    pub fn logs(&self) -> Result<String, CommandError> {
+        let output = self
+            .backend_command()
+            .arguments(["container", "logs"])
+            .argument(&self.id)
+            .output()?;
+
+        if !output.success() {
+            return Err(CommandError {
+                io_error: None,
+                exit_status: Some(output.status),
+            });
+        }
+
+        let mut logs = String::from_utf8_lossy(&output.stdout).into_owned();
+        logs.push_str(&String::from_utf8_lossy(&output.stderr));
+
+        Ok(logs)
+    }
+
     #[must_use]
     pub fn inspect(&self) -> serde_json::Value {
         let stdout = self
@@ -833,6 +984,22 @@ impl Container {
             .to_string()
     }
 
+    /// Whether the container is currently running
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.inspect_format("{{.State.Running}}") == "true"
+    }
+
+    /// Read the environment variables the container was started with
+    #[must_use]
+    pub fn environment(&self) -> std::collections::BTreeMap<String, String> {
+        self.inspect_format("{{range .Config.Env}}{{println .}}{{end}}")
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
     #[must_use]
     pub fn read_host_tcp_port(&self, container_port: u16) -> Option<u16> {
         let json = self.inspect();
@@ -110,6 +110,23 @@ impl_from_str!(
     Reference,
 );
 
+macro_rules! impl_deserialize {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl<'de> serde::Deserialize<'de> for $type {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    <String as serde::Deserialize<'de>>::deserialize(deserializer)
+                        .and_then(|value| value.parse().map_err(serde::de::Error::custom))
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize!(Domain, Path);
+
 /// A domain component: alphanumeric, optionally with hyphens between alphanumerics.
 ///
 /// Pattern: `([a-zA-Z0-9]|[a-zA-Z0-9][a-zA-Z0-9-]*[a-zA-Z0-9])`
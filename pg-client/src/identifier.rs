@@ -490,6 +490,20 @@ impl Role {
 /// A user is a role with the `LOGIN` attribute.
 pub type User = Role;
 
+/// A table name qualified by its schema.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct QualifiedName {
+    pub schema: Schema,
+    pub table: Table,
+}
+
+impl QualifiedName {
+    #[must_use]
+    pub fn new(schema: Schema, table: Table) -> Self {
+        Self { schema, table }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
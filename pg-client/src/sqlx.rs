@@ -2,8 +2,8 @@ pub mod analyze;
 pub mod partitioned_index;
 
 use crate::{
-    Config, Endpoint, PGAPPNAME, PGCHANNELBINDING, PGHOSTADDR, PGPASSWORD, PGPORT, PGSSLROOTCERT,
-    SslMode,
+    Config, Endpoint, PGAPPNAME, PGCHANNELBINDING, PGHOSTADDR, PGPASSWORD, PGPORT, PGREPLICATION,
+    PGSSLROOTCERT, SslMode,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,7 +32,7 @@ impl std::fmt::Display for OptionsError {
             ),
             Self::SslRootCertSystemNotSupported => write!(
                 f,
-                "`SslRootCert::System` is not supported by sqlx, which expects a file path for `ssl_root_cert`"
+                "`SslRootCert::System` is not supported by sqlx, which expects a file path for `ssl_root_cert`, see `Config::resolve_system_roots` behind the `system-roots` feature"
             ),
         }
     }
@@ -50,6 +50,15 @@ pub enum ConnectionError {
 
     #[error("Failed to close database connection")]
     Close(#[source] sqlx::Error),
+
+    #[error("Failed to begin transaction")]
+    Begin(#[source] sqlx::Error),
+
+    #[error("Failed to commit transaction")]
+    Commit(#[source] sqlx::Error),
+
+    #[error("Failed to roll back transaction")]
+    Rollback(#[source] sqlx::Error),
 }
 
 impl From<&SslMode> for sqlx::postgres::PgSslMode {
@@ -107,6 +116,7 @@ impl Config {
     ///         port: Some(Port::new(5432)),
     ///     },
     ///     password: Some(Password::from_str("some-password").unwrap()),
+    ///     replication: None,
     ///     ssl_mode: SslMode::VerifyFull,
     ///     ssl_root_cert: Some(SslRootCert::File("/some.pem".into())),
     ///     user: User::from_static_or_panic("some-user"),
@@ -197,6 +207,15 @@ impl Config {
             reject_env(&PGPASSWORD, "password")?;
         }
 
+        if self.replication.is_some() {
+            return Err(OptionsError::UnsupportedFeature {
+                env_key: PGREPLICATION.as_str().to_string(),
+                field_name: "replication".to_string(),
+            });
+        } else {
+            reject_env(&PGREPLICATION, "replication")?;
+        }
+
         if let Some(ssl_root_cert) = &self.ssl_root_cert {
             match ssl_root_cert {
                 crate::SslRootCert::File(path) => {
@@ -231,12 +250,65 @@ impl Config {
 
         Ok(result)
     }
+
+    /// Connect a pool of up to `max_connections` connections.
+    ///
+    /// Connections are opened lazily as the pool is used, only the first one is
+    /// established before returning. Close the pool via [`sqlx::PgPool::close`].
+    pub async fn connect_sqlx_pool(
+        &self,
+        max_connections: u32,
+    ) -> Result<sqlx::PgPool, ConnectionError> {
+        let config = self.to_sqlx_connect_options()?;
+
+        sqlx::postgres::PgPoolOptions::new()
+            .max_connections(max_connections)
+            .connect_with(config)
+            .await
+            .map_err(ConnectionError::Connect)
+    }
+
+    /// Run `action` inside a transaction on a fresh connection.
+    ///
+    /// The transaction is committed when `action` returns `Ok` and rolled back when it
+    /// returns `Err`. Should `action` panic, the transaction is dropped without being
+    /// committed, which rolls it back. The action's own result is returned as is.
+    pub async fn with_sqlx_transaction<
+        T,
+        E,
+        F: AsyncFnMut(&mut sqlx::PgTransaction<'_>) -> Result<T, E>,
+    >(
+        &self,
+        mut action: F,
+    ) -> Result<Result<T, E>, ConnectionError> {
+        self.with_sqlx_connection(async |connection| {
+            let mut transaction = sqlx::Connection::begin(connection)
+                .await
+                .map_err(ConnectionError::Begin)?;
+
+            let result = action(&mut transaction).await;
+
+            match result {
+                Ok(_) => transaction
+                    .commit()
+                    .await
+                    .map_err(ConnectionError::Commit)?,
+                Err(_) => transaction
+                    .rollback()
+                    .await
+                    .map_err(ConnectionError::Rollback)?,
+            }
+
+            Ok(result)
+        })
+        .await?
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Database, Endpoint, Host, Port, SslMode, SslRootCert, User};
+    use crate::{Database, Endpoint, Host, Port, Replication, SslMode, SslRootCert, User};
     use std::str::FromStr;
 
     const TEST_DATABASE: Database = Database::from_static_or_panic("some-database");
@@ -254,6 +326,7 @@ mod tests {
                 port: Some(Port::new(5432)),
             },
             password: None,
+            replication: None,
             ssl_mode: SslMode::VerifyFull,
             ssl_root_cert: Some(SslRootCert::System),
             user: TEST_USER,
@@ -266,4 +339,31 @@ mod tests {
             Err(OptionsError::SslRootCertSystemNotSupported)
         ));
     }
+
+    #[test]
+    fn test_replication_not_supported() {
+        let config = Config {
+            application_name: None,
+            database: TEST_DATABASE,
+            endpoint: Endpoint::Network {
+                host: Host::from_str("localhost").unwrap(),
+                channel_binding: None,
+                host_addr: None,
+                port: Some(Port::new(5432)),
+            },
+            password: None,
+            replication: Some(Replication::Database),
+            ssl_mode: SslMode::VerifyFull,
+            ssl_root_cert: None,
+            user: TEST_USER,
+        };
+
+        assert_eq!(
+            config.to_sqlx_connect_options().unwrap_err(),
+            OptionsError::UnsupportedFeature {
+                env_key: "PGREPLICATION".to_string(),
+                field_name: "replication".to_string(),
+            }
+        );
+    }
 }
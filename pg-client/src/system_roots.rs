@@ -0,0 +1,170 @@
+//! Bridge [`SslRootCert::System`](crate::SslRootCert::System) to clients that only accept a
+//! root certificate file, such as sqlx.
+//!
+//! ```no_run
+//! # fn example(config: pg_client::Config) -> Result<(), pg_client::system_roots::Error> {
+//! let bundle = pg_client::system_roots::Bundle::create()?;
+//! let config = config.resolve_system_roots(&bundle);
+//! // Keep `bundle` alive while connections are being established.
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("No platform root certificates found: {errors:?}")]
+    NoCertificates { errors: Vec<String> },
+
+    #[error("Could not write root certificate bundle {}", path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// PEM file holding the platform's root certificates, removed on drop.
+#[derive(Debug)]
+pub struct Bundle {
+    path: PathBuf,
+}
+
+impl Bundle {
+    /// Gather the platform's root certificates into a new temporary PEM file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no root certificates could be loaded or the file cannot be written.
+    pub fn create() -> Result<Self, Error> {
+        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+        let result = rustls_native_certs::load_native_certs();
+
+        if result.certs.is_empty() {
+            return Err(Error::NoCertificates {
+                errors: result.errors.iter().map(ToString::to_string).collect(),
+            });
+        }
+
+        let pems: Vec<pem::Pem> = result
+            .certs
+            .iter()
+            .map(|cert| pem::Pem::new("CERTIFICATE", cert.to_vec()))
+            .collect();
+
+        let path = std::env::temp_dir().join(format!(
+            "pg-client-system-roots-{}-{}.pem",
+            std::process::id(),
+            COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        ));
+
+        write_private(&path, pem::encode_many(&pems).as_bytes()).map_err(|source| {
+            Error::Write {
+                path: path.clone(),
+                source,
+            }
+        })?;
+
+        Ok(Self { path })
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Write `contents` to a new file only the current user can read
+///
+/// Fails when `path` already exists, so a file or symlink planted at the predictable temp
+/// path is never written through.
+fn write_private(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        options.mode(0o600);
+    }
+
+    options.open(path)?.write_all(contents)
+}
+
+impl Drop for Bundle {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl crate::Config {
+    /// Replace [`SslRootCert::System`](crate::SslRootCert::System) with the file of `bundle`.
+    ///
+    /// Other root certificate settings are returned unchanged.
+    #[must_use]
+    pub fn resolve_system_roots(self, bundle: &Bundle) -> Self {
+        match self.ssl_root_cert {
+            Some(crate::SslRootCert::System) => Self {
+                ssl_root_cert: Some(crate::SslRootCert::File(bundle.path().to_path_buf())),
+                ..self
+            },
+            _ => self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bundle_create() {
+        let bundle = Bundle::create().unwrap();
+        let path = bundle.path().to_path_buf();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let certificates = pem::parse_many(&contents).unwrap();
+
+        assert!(!certificates.is_empty());
+        assert!(certificates.iter().all(|pem| pem.tag() == "CERTIFICATE"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        drop(bundle);
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn resolve_system_roots() {
+        let bundle = Bundle::create().unwrap();
+
+        let config = crate::Config {
+            application_name: None,
+            database: crate::Database::POSTGRES,
+            endpoint: crate::Endpoint::SocketPath("/socket".into()),
+            password: None,
+            replication: None,
+            ssl_mode: crate::SslMode::VerifyFull,
+            ssl_root_cert: Some(crate::SslRootCert::System),
+            user: crate::User::POSTGRES,
+        };
+
+        assert_eq!(
+            config.resolve_system_roots(&bundle).ssl_root_cert,
+            Some(crate::SslRootCert::File(bundle.path().to_path_buf()))
+        );
+    }
+}
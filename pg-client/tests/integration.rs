@@ -268,6 +268,7 @@ async fn test_with_sqlx_connection_error_on_unavailable_database() {
             port: Some(pg_client::Port::new(0)), // Port 0 is reserved and never available
         },
         password: Some("test".parse().unwrap()),
+        replication: None,
         ssl_mode: pg_client::SslMode::Disable,
         ssl_root_cert: None,
         user: TEST_USER,
@@ -296,6 +297,65 @@ async fn test_with_sqlx_connection_error_on_unavailable_database() {
     }
 }
 
+#[tokio::test]
+async fn test_with_sqlx_transaction() {
+    let backend = ociman::test_backend_setup!();
+    let definition = definition(backend);
+
+    definition
+        .with_container(async |container| {
+            let config = container.client_config();
+
+            config
+                .with_sqlx_connection(async |connection| {
+                    sqlx::query("CREATE TABLE events (id int)")
+                        .execute(connection)
+                        .await
+                        .unwrap();
+                })
+                .await
+                .unwrap();
+
+            let committed = config
+                .with_sqlx_transaction(async |transaction| {
+                    sqlx::query("INSERT INTO events VALUES (1)")
+                        .execute(&mut **transaction)
+                        .await
+                })
+                .await
+                .unwrap();
+
+            assert!(committed.is_ok(), "Insert should succeed: {committed:?}");
+
+            let rolled_back = config
+                .with_sqlx_transaction(async |transaction| {
+                    sqlx::query("INSERT INTO events VALUES (2)")
+                        .execute(&mut **transaction)
+                        .await
+                        .unwrap();
+
+                    Err::<(), &str>("abort")
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(rolled_back, Err("abort"));
+
+            let ids: Vec<i32> = config
+                .with_sqlx_connection(async |connection| {
+                    sqlx::query_scalar("SELECT id FROM events ORDER BY id")
+                        .fetch_all(connection)
+                        .await
+                        .unwrap()
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(ids, vec![1]);
+        })
+        .await
+}
+
 #[tokio::test]
 async fn test_analyze_all_tables() {
     let backend = ociman::test_backend_setup!();
@@ -169,6 +169,85 @@ impl Bundle {
     }
 }
 
+/// Generated bundles by hostname, kept for the process lifetime
+static GENERATED: std::sync::LazyLock<
+    std::sync::Mutex<std::collections::HashMap<String, GeneratedEntry>>,
+> = std::sync::LazyLock::new(Default::default);
+
+struct GeneratedEntry {
+    bundle: std::sync::Arc<Bundle>,
+    ca_cert_file: std::sync::Weak<CaCertFile>,
+}
+
+/// A generated bundle shared by every container using the same hostname
+pub(crate) struct Generated {
+    pub(crate) bundle: std::sync::Arc<Bundle>,
+    pub(crate) ca_cert_file: std::sync::Arc<CaCertFile>,
+}
+
+/// The CA certificate of a generated bundle written to a temp file
+///
+/// The file is removed once the last container holding it is stopped or dropped, and written
+/// again when the cached bundle is next used.
+#[derive(Debug)]
+pub(crate) struct CaCertFile {
+    path: std::path::PathBuf,
+}
+
+impl CaCertFile {
+    /// Write `ca_cert_pem` to a new temp file
+    pub(crate) fn write(ca_cert_pem: &str) -> Self {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("pg_ephemeral_ca_{timestamp}.crt"));
+        std::fs::write(&path, ca_cert_pem).expect("Failed to write CA certificate to temp file");
+
+        Self { path }
+    }
+
+    pub(crate) fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+
+impl Drop for CaCertFile {
+    fn drop(&mut self) {
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            log::warn!(
+                "Failed to remove CA certificate temp file {}: {error}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// Return the generated bundle for `hostname`, generating it on first use
+pub(crate) fn generated(hostname: &str) -> Generated {
+    let mut cache = GENERATED.lock().unwrap();
+
+    let entry = cache
+        .entry(hostname.to_string())
+        .or_insert_with(|| GeneratedEntry {
+            bundle: std::sync::Arc::new(
+                Bundle::generate(hostname).expect("Failed to generate SSL certificate bundle"),
+            ),
+            ca_cert_file: std::sync::Weak::new(),
+        });
+
+    let ca_cert_file = entry.ca_cert_file.upgrade().unwrap_or_else(|| {
+        let ca_cert_file = std::sync::Arc::new(CaCertFile::write(&entry.bundle.ca_cert_pem));
+        entry.ca_cert_file = std::sync::Arc::downgrade(&ca_cert_file);
+        ca_cert_file
+    });
+
+    Generated {
+        bundle: entry.bundle.clone(),
+        ca_cert_file,
+    }
+}
+
 #[derive(Debug)]
 pub enum ValidationError {
     ParseError(String),
@@ -203,4 +282,34 @@ mod test {
         )
         .unwrap();
     }
+
+    #[test]
+    fn test_generated_reuses_bundle() {
+        let hostname = "reuse.test.example.com";
+
+        let first = generated(hostname);
+        let second = generated(hostname);
+
+        assert!(std::sync::Arc::ptr_eq(&first.bundle, &second.bundle));
+        assert!(std::sync::Arc::ptr_eq(
+            &first.ca_cert_file,
+            &second.ca_cert_file
+        ));
+
+        let path = first.ca_cert_file.path().to_path_buf();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            first.bundle.ca_cert_pem
+        );
+
+        let bundle = first.bundle.clone();
+        drop(first);
+        assert!(path.exists());
+        drop(second);
+        assert!(!path.exists());
+
+        let third = generated(hostname);
+        assert!(std::sync::Arc::ptr_eq(&bundle, &third.bundle));
+        assert!(third.ca_cert_file.path().exists());
+    }
 }
@@ -1,7 +1,10 @@
 use crate::config::Config;
 use crate::{InstanceMap, InstanceName};
 
-#[derive(Clone, Debug, clap::ValueEnum)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum Protocol {
     V0,
 }
@@ -60,13 +63,36 @@ pub struct App {
 impl App {
     pub async fn run(&self) {
         let overwrites = crate::config::InstanceDefinition {
+            application_name: None,
             backend: self.backend,
+            cross_container_access: None,
+            database: None,
+            additional_databases: Vec::new(),
+            encoding: None,
+            extensions: Vec::new(),
+            bind_address: None,
+            memory_limit: None,
+            cpu_limit: None,
+            host_port: None,
+            initdb_args: Vec::new(),
+            keep_on_failure: None,
+            persist: None,
+            locale: None,
+            network: None,
             image: self.image.clone(),
+            image_registry: None,
+            image_repository: None,
+            parallel_seeds: None,
             seeds: indexmap::IndexMap::new(),
+            server_parameters: std::collections::BTreeMap::new(),
             ssl_config: self
                 .ssl_hostname
                 .clone()
-                .map(|hostname| crate::config::SslConfigDefinition { hostname }),
+                .map(|hostname| crate::config::SslConfigDefinition::Generated { hostname }),
+            snapshot_cache: None,
+            storage: None,
+            superuser: None,
+            connect_attempt_timeout: None,
             wait_available_timeout: None,
         };
 
@@ -107,6 +133,12 @@ impl App {
             }
         };
 
+        let result = result.and_then(|instance_map| {
+            crate::config::validate_seeds(&instance_map)
+                .map(|()| instance_map)
+                .map_err(|error| format!("Invalid config: {error}"))
+        });
+
         match result {
             Ok(instance_map) => {
                 self.command
@@ -170,7 +202,8 @@ pub enum Command {
     /// Intent to be used for automation with other languages wrapping pg-ephemeral.
     ///
     /// After successful boot this command will print a single line to stdout containing a JSON
-    /// representation of the root connection details.
+    /// representation of the root connection details, see
+    /// [`crate::IntegrationServerHandshake`].
     ///
     /// The server will stop once stdin returns EOF, aka the parent process closed it.
     #[command(name = "integration-server")]
@@ -264,19 +297,15 @@ impl Command {
         match self {
             Self::Cache { instance, command } => match command {
                 CacheCommand::Status { verbose } => {
-                    let instance_name = instance.clone().unwrap_or_default();
-                    let definition = Self::get_instance(instance_map, instance)
-                        .definition()
-                        .unwrap();
-                    definition.print_cache_status(&instance_name.to_string(), *verbose)
+                    let definition = Self::get_definition(instance_map, instance);
+                    definition.print_cache_status(&definition.instance_name.0, *verbose)
                 }
                 CacheCommand::Reset => {
-                    let instance_name = instance.clone().unwrap_or_default();
-                    let definition = Self::get_instance(instance_map, instance)
-                        .definition()
-                        .unwrap();
+                    let definition = Self::get_definition(instance_map, instance);
                     let name: ociman::reference::Name =
-                        format!("pg-ephemeral/{instance_name}").parse().unwrap();
+                        format!("pg-ephemeral/{}", definition.instance_name)
+                            .parse()
+                            .unwrap();
                     let references = definition.backend.image_references_by_name(&name);
                     for reference in &references {
                         definition.backend.remove_image(reference);
@@ -285,30 +314,22 @@ impl Command {
                 }
             },
             Self::ContainerPsql { instance } => {
-                let definition = Self::get_instance(instance_map, instance)
-                    .definition()
-                    .unwrap();
+                let definition = Self::get_definition(instance_map, instance);
                 definition.with_container(container_psql).await
             }
             Self::ContainerSchemaDump { instance } => {
-                let definition = Self::get_instance(instance_map, instance)
-                    .definition()
-                    .unwrap();
+                let definition = Self::get_definition(instance_map, instance);
                 definition.with_container(container_schema_dump).await
             }
             Self::ContainerShell { instance } => {
-                let definition = Self::get_instance(instance_map, instance)
-                    .definition()
-                    .unwrap();
+                let definition = Self::get_definition(instance_map, instance);
                 definition.with_container(container_shell).await
             }
             Self::IntegrationServer {
                 instance,
                 protocol: _,
             } => {
-                let definition = Self::get_instance(instance_map, instance)
-                    .definition()
-                    .unwrap();
+                let definition = Self::get_definition(instance_map, instance);
                 definition.run_integration_server().await
             }
             Self::List => {
@@ -317,9 +338,7 @@ impl Command {
                 }
             }
             Self::Psql { instance } => {
-                let definition = Self::get_instance(instance_map, instance)
-                    .definition()
-                    .unwrap();
+                let definition = Self::get_definition(instance_map, instance);
                 definition
                     .with_container(host_psql)
                     .await
@@ -330,9 +349,7 @@ impl Command {
                 command,
                 arguments,
             } => {
-                let definition = Self::get_instance(instance_map, instance)
-                    .definition()
-                    .unwrap();
+                let definition = Self::get_definition(instance_map, instance);
                 definition
                     .with_container(async |container| {
                         host_command(container, command, arguments).await
@@ -344,6 +361,18 @@ impl Command {
         }
     }
 
+    fn get_definition(
+        instance_map: &InstanceMap,
+        instance: &Option<InstanceName>,
+    ) -> crate::Definition {
+        let instance_name = instance.clone().unwrap_or_default();
+
+        Self::get_instance(instance_map, instance)
+            .definition()
+            .unwrap()
+            .instance_name(instance_name)
+    }
+
     fn get_instance<'a>(
         instance_map: &'a InstanceMap,
         instance: &Option<InstanceName>,
@@ -380,7 +409,10 @@ async fn container_psql(container: &crate::container::Container) {
 }
 
 async fn container_schema_dump(container: &crate::container::Container) {
-    println!("{}", container.exec_schema_dump());
+    println!(
+        "{}",
+        container.exec_schema_dump(&crate::SchemaDumpOptions::default())
+    );
 }
 
 async fn container_shell(container: &crate::container::Container) {
@@ -1,18 +1,43 @@
 use super::InstanceName;
-use crate::definition::{Definition, SslConfig};
+use crate::definition::{
+    CpuLimit, Definition, MemoryLimit, ServerParameterName, SslConfig, Storage,
+};
 use crate::image::Image;
-use crate::seed::{Command, CommandCacheConfig, Seed, SeedName};
+use crate::seed::{Command, CommandCacheConfig, RestoreFormat, Seed, SeedName};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Instance {
+    pub additional_databases: Vec<pg_client::Database>,
     pub application_name: Option<pg_client::ApplicationName>,
     pub backend: ociman::backend::Selection,
     pub database: pg_client::Database,
+    pub encoding: Option<String>,
+    pub extensions: Vec<pg_client::identifier::Extension>,
+    pub initdb_args: Vec<String>,
+    pub keep_on_failure: bool,
+    pub locale: Option<String>,
     pub seeds: indexmap::IndexMap<SeedName, Seed>,
+    /// Seeds that have to be applied before the keyed seed
+    pub seed_dependencies: std::collections::BTreeMap<SeedName, Vec<SeedName>>,
+    pub server_parameters: std::collections::BTreeMap<ServerParameterName, String>,
+    pub snapshot_cache: bool,
     pub ssl_config: Option<SslConfig>,
+    pub storage: Storage,
     pub superuser: pg_client::User,
     pub image: Image,
     pub cross_container_access: bool,
+    pub host_port: Option<pg_client::Port>,
+    /// Host address the container port is published on, overriding `cross_container_access`
+    pub bind_address: Option<std::net::IpAddr>,
+    pub memory_limit: Option<MemoryLimit>,
+    pub cpu_limit: Option<CpuLimit>,
+    pub network: Option<String>,
+    pub parallel_seeds: Option<std::num::NonZeroU16>,
+    /// Keep the container running after the handle is dropped, named after the instance
+    pub persist: bool,
+    /// Bound on a single readiness probe attempt, a fraction of `wait_available_timeout`
+    /// when `None`
+    pub connect_attempt_timeout: Option<std::time::Duration>,
     pub wait_available_timeout: std::time::Duration,
 }
 
@@ -22,28 +47,102 @@ impl Instance {
         Self {
             backend,
             application_name: None,
+            encoding: None,
+            initdb_args: Vec::new(),
+            keep_on_failure: false,
+            locale: None,
             seeds: indexmap::IndexMap::new(),
+            seed_dependencies: std::collections::BTreeMap::new(),
+            server_parameters: std::collections::BTreeMap::new(),
+            snapshot_cache: false,
             ssl_config: None,
+            storage: Storage::Disk,
             superuser: pg_client::User::POSTGRES,
             database: pg_client::Database::POSTGRES,
+            additional_databases: Vec::new(),
+            extensions: Vec::new(),
             image,
             cross_container_access: false,
+            host_port: None,
+            bind_address: None,
+            memory_limit: None,
+            cpu_limit: None,
+            network: None,
+            parallel_seeds: None,
+            persist: false,
+            connect_attempt_timeout: None,
             wait_available_timeout: std::time::Duration::from_secs(10),
         }
     }
 
+    /// Check that seeds read from the local filesystem point to readable paths
+    ///
+    /// Gives feedback on broken paths before a container is started. Git revision seeds are
+    /// exempt as their paths are only resolved when the seeds are loaded.
+    pub fn validate_seeds(&self, instance_name: &InstanceName) -> Result<(), Error> {
+        for (seed_name, seed) in &self.seeds {
+            let (path, result) = match seed {
+                Seed::SqlFile { path, .. } | Seed::Restore { path, .. } => {
+                    (path, check_readable_file(path))
+                }
+                Seed::SqlDirectory { path, .. } => (path, std::fs::read_dir(path).map(drop)),
+                Seed::SqlFileGitRevision { .. } | Seed::Command { .. } | Seed::Script { .. } => {
+                    continue;
+                }
+            };
+
+            result.map_err(|error| Error::SeedPath {
+                instance_name: instance_name.clone(),
+                seed_name: seed_name.clone(),
+                path: path.clone(),
+                error: error.into(),
+            })?;
+        }
+
+        Ok(())
+    }
+
     pub fn definition(&self) -> Result<Definition, ociman::backend::resolve::Error> {
-        Ok(Definition {
+        self.backend
+            .resolve()
+            .map(|backend| self.definition_with_backend(backend))
+    }
+
+    /// Definition of the instance on an already resolved backend
+    #[must_use]
+    pub fn definition_with_backend(&self, backend: ociman::Backend) -> Definition {
+        Definition {
             application_name: self.application_name.clone(),
-            backend: self.backend.resolve()?,
+            backend,
             database: self.database.clone(),
+            additional_databases: self.additional_databases.clone(),
+            encoding: self.encoding.clone(),
+            extensions: self.extensions.clone(),
+            initdb_args: self.initdb_args.clone(),
+            keep_on_failure: self.keep_on_failure,
+            locale: self.locale.clone(),
             seeds: self.seeds.clone(),
+            seed_dependencies: self.seed_dependencies.clone(),
+            server_parameters: self.server_parameters.clone(),
+            snapshot_cache: self.snapshot_cache,
             ssl_config: self.ssl_config.clone(),
+            storage: self.storage,
             superuser: self.superuser.clone(),
             image: self.image.clone(),
             cross_container_access: self.cross_container_access,
+            host_port: self.host_port,
+            bind_address: self.bind_address,
+            memory_limit: self.memory_limit.clone(),
+            cpu_limit: self.cpu_limit,
+            network: self.network.clone(),
+            parallel_seeds: self.parallel_seeds,
+            persist: self.persist,
+            instance_name: crate::InstanceName::default(),
+            readiness_probe: crate::definition::ReadinessProbe::Connect,
+            stop_timeout: crate::definition::DEFAULT_STOP_TIMEOUT,
+            connect_attempt_timeout: self.connect_attempt_timeout,
             wait_available_timeout: self.wait_available_timeout,
-        })
+        }
     }
 }
 
@@ -58,6 +157,37 @@ pub enum Error {
         instance_name: InstanceName,
         field: &'static str,
     },
+    #[error("Instance {instance_name} seed {seed_name} path {} is not readable: {error}", .path.display())]
+    SeedPath {
+        instance_name: InstanceName,
+        seed_name: SeedName,
+        path: std::path::PathBuf,
+        error: IoError,
+    },
+    #[error("Unknown instance: {0}")]
+    UnknownInstance(InstanceName),
+    #[error(
+        "Expected exactly one instance, config defines: [{}]",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    NotSingleInstance(Vec<InstanceName>),
+    #[error("Could not resolve backend: {0}")]
+    Backend(#[from] ociman::backend::resolve::Error),
+}
+
+/// Check the seed paths of every instance, see [`Instance::validate_seeds`]
+pub fn validate_seeds(instance_map: &super::InstanceMap) -> Result<(), Error> {
+    instance_map
+        .iter()
+        .try_for_each(|(instance_name, instance)| instance.validate_seeds(instance_name))
+}
+
+fn check_readable_file(path: &std::path::Path) -> std::io::Result<()> {
+    if std::fs::File::open(path)?.metadata()?.is_dir() {
+        return Err(std::io::ErrorKind::IsADirectory.into());
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, PartialEq)]
@@ -83,52 +213,208 @@ pub enum SeedConfig {
     SqlFile {
         path: std::path::PathBuf,
         git_revision: Option<String>,
+        database: Option<pg_client::Database>,
+        #[serde(default)]
+        after: Vec<SeedName>,
+    },
+    SqlDirectory {
+        path: std::path::PathBuf,
+        pattern: Option<String>,
+        database: Option<pg_client::Database>,
+        #[serde(default)]
+        after: Vec<SeedName>,
+    },
+    Restore {
+        path: std::path::PathBuf,
+        #[serde(default)]
+        format: RestoreFormat,
+        database: Option<pg_client::Database>,
+        #[serde(default)]
+        after: Vec<SeedName>,
     },
     Command {
         command: String,
         #[serde(default)]
         arguments: Vec<String>,
+        #[serde(default, deserialize_with = "deserialize_env")]
+        env: std::collections::BTreeMap<cmd_proc::EnvVariableName<'static>, String>,
+        working_dir: Option<std::path::PathBuf>,
         cache: CommandCacheConfig,
+        database: Option<pg_client::Database>,
+        #[serde(default)]
+        after: Vec<SeedName>,
     },
     Script {
         script: String,
+        database: Option<pg_client::Database>,
+        #[serde(default)]
+        after: Vec<SeedName>,
     },
 }
 
+impl SeedConfig {
+    /// Seeds that have to be applied before this one
+    #[must_use]
+    pub fn after(&self) -> &[SeedName] {
+        match self {
+            Self::SqlFile { after, .. }
+            | Self::SqlDirectory { after, .. }
+            | Self::Restore { after, .. }
+            | Self::Command { after, .. }
+            | Self::Script { after, .. } => after,
+        }
+    }
+}
+
 impl From<SeedConfig> for Seed {
     fn from(value: SeedConfig) -> Self {
         match value {
-            SeedConfig::SqlFile { path, git_revision } => match git_revision {
-                Some(git_revision) => Seed::SqlFileGitRevision { git_revision, path },
-                None => Seed::SqlFile { path },
+            SeedConfig::SqlFile {
+                path,
+                git_revision,
+                database,
+                after: _,
+            } => match git_revision {
+                Some(git_revision) => Seed::SqlFileGitRevision {
+                    git_revision,
+                    path,
+                    database,
+                },
+                None => Seed::SqlFile { path, database },
+            },
+            SeedConfig::SqlDirectory {
+                path,
+                pattern,
+                database,
+                after: _,
+            } => Seed::SqlDirectory {
+                path,
+                pattern,
+                database,
+            },
+            SeedConfig::Restore {
+                path,
+                format,
+                database,
+                after: _,
+            } => Seed::Restore {
+                path,
+                format,
+                database,
             },
             SeedConfig::Command {
                 command,
                 arguments,
+                env,
+                working_dir,
                 cache,
+                database,
+                after: _,
             } => Seed::Command {
-                command: Command::new(command, arguments),
+                command: Command {
+                    env,
+                    working_dir,
+                    ..Command::new(command, arguments)
+                },
                 cache,
+                database,
             },
-            SeedConfig::Script { script } => Seed::Script { script },
+            SeedConfig::Script {
+                script,
+                database,
+                after: _,
+            } => Seed::Script { script, database },
         }
     }
 }
 
+fn deserialize_env<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<std::collections::BTreeMap<cmd_proc::EnvVariableName<'static>, String>, D::Error> {
+    let env: std::collections::BTreeMap<String, String> =
+        serde::Deserialize::deserialize(deserializer)?;
+
+    env.into_iter()
+        .map(|(key, value)| {
+            key.parse().map(|key| (key, value)).map_err(|error| {
+                serde::de::Error::custom(format!(
+                    "Invalid environment variable name {key:?}: {error}"
+                ))
+            })
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
-pub struct SslConfigDefinition {
-    pub hostname: pg_client::HostName,
+#[serde(deny_unknown_fields, untagged)]
+pub enum SslConfigDefinition {
+    UserProvided {
+        hostname: pg_client::HostName,
+        ca_cert: std::path::PathBuf,
+        server_cert: std::path::PathBuf,
+        server_key: std::path::PathBuf,
+    },
+    Generated {
+        hostname: pg_client::HostName,
+    },
+}
+
+impl From<SslConfigDefinition> for SslConfig {
+    fn from(value: SslConfigDefinition) -> Self {
+        match value {
+            SslConfigDefinition::UserProvided {
+                hostname,
+                ca_cert,
+                server_cert,
+                server_key,
+            } => SslConfig::UserProvided {
+                hostname,
+                ca_cert,
+                server_cert,
+                server_key,
+            },
+            SslConfigDefinition::Generated { hostname } => SslConfig::Generated { hostname },
+        }
+    }
 }
 
-#[derive(Debug, serde::Deserialize, PartialEq)]
+#[derive(Clone, Debug, serde::Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct InstanceDefinition {
+    #[serde(default)]
+    pub additional_databases: Vec<pg_client::Database>,
+    pub application_name: Option<pg_client::ApplicationName>,
     pub backend: Option<ociman::backend::Selection>,
+    pub cross_container_access: Option<bool>,
+    pub database: Option<pg_client::Database>,
+    pub encoding: Option<String>,
+    #[serde(default)]
+    pub extensions: Vec<pg_client::identifier::Extension>,
+    pub host_port: Option<pg_client::Port>,
+    pub bind_address: Option<std::net::IpAddr>,
+    pub memory_limit: Option<MemoryLimit>,
+    #[serde(alias = "cpus")]
+    pub cpu_limit: Option<CpuLimit>,
+    #[serde(default)]
+    pub initdb_args: Vec<String>,
+    pub keep_on_failure: Option<bool>,
+    pub locale: Option<String>,
+    pub network: Option<String>,
     pub image: Option<Image>,
+    pub image_registry: Option<ociman::reference::Domain>,
+    pub image_repository: Option<ociman::reference::Path>,
+    pub parallel_seeds: Option<std::num::NonZeroU16>,
+    pub persist: Option<bool>,
     #[serde(default)]
     pub seeds: indexmap::IndexMap<SeedName, SeedConfig>,
+    #[serde(default)]
+    pub server_parameters: std::collections::BTreeMap<ServerParameterName, String>,
+    pub snapshot_cache: Option<bool>,
     pub ssl_config: Option<SslConfigDefinition>,
+    pub storage: Option<Storage>,
+    pub superuser: Option<pg_client::User>,
+    #[serde(default, with = "humantime_serde")]
+    pub connect_attempt_timeout: Option<std::time::Duration>,
     #[serde(default, with = "humantime_serde")]
     pub wait_available_timeout: Option<std::time::Duration>,
 }
@@ -137,10 +423,33 @@ impl InstanceDefinition {
     #[must_use]
     pub fn empty() -> Self {
         Self {
+            application_name: None,
             backend: None,
+            cross_container_access: None,
+            database: None,
+            additional_databases: Vec::new(),
+            encoding: None,
+            extensions: Vec::new(),
+            host_port: None,
+            bind_address: None,
+            memory_limit: None,
+            cpu_limit: None,
+            initdb_args: Vec::new(),
+            keep_on_failure: None,
+            locale: None,
+            network: None,
             image: None,
+            image_registry: None,
+            image_repository: None,
+            parallel_seeds: None,
+            persist: None,
             seeds: indexmap::IndexMap::new(),
+            server_parameters: std::collections::BTreeMap::new(),
+            snapshot_cache: None,
             ssl_config: None,
+            storage: None,
+            superuser: None,
+            connect_attempt_timeout: None,
             wait_available_timeout: None,
         }
     }
@@ -166,12 +475,134 @@ impl InstanceDefinition {
             }
         };
 
+        let image = match overwrites
+            .image_registry
+            .as_ref()
+            .or(self.image_registry.as_ref())
+            .or(defaults.image_registry.as_ref())
+        {
+            Some(registry) => image.registry(registry.clone()),
+            None => image,
+        };
+
+        let image = match overwrites
+            .image_repository
+            .as_ref()
+            .or(self.image_repository.as_ref())
+            .or(defaults.image_repository.as_ref())
+        {
+            Some(repository) => image.repository(repository.clone()),
+            None => image,
+        };
+
         let backend = overwrites
             .backend
             .or(self.backend)
             .or(defaults.backend)
             .unwrap_or(ociman::backend::Selection::Auto);
 
+        let application_name = overwrites
+            .application_name
+            .as_ref()
+            .or(self.application_name.as_ref())
+            .or(defaults.application_name.as_ref())
+            .cloned();
+
+        let cross_container_access = overwrites
+            .cross_container_access
+            .or(self.cross_container_access)
+            .or(defaults.cross_container_access)
+            .unwrap_or(false);
+
+        let snapshot_cache = overwrites
+            .snapshot_cache
+            .or(self.snapshot_cache)
+            .or(defaults.snapshot_cache)
+            .unwrap_or(false);
+
+        let host_port = overwrites
+            .host_port
+            .or(self.host_port)
+            .or(defaults.host_port);
+
+        let bind_address = overwrites
+            .bind_address
+            .or(self.bind_address)
+            .or(defaults.bind_address);
+
+        let memory_limit = overwrites
+            .memory_limit
+            .as_ref()
+            .or(self.memory_limit.as_ref())
+            .or(defaults.memory_limit.as_ref())
+            .cloned();
+
+        let cpu_limit = overwrites
+            .cpu_limit
+            .or(self.cpu_limit)
+            .or(defaults.cpu_limit);
+
+        let keep_on_failure = overwrites
+            .keep_on_failure
+            .or(self.keep_on_failure)
+            .or(defaults.keep_on_failure)
+            .unwrap_or(false);
+
+        let encoding = overwrites
+            .encoding
+            .as_ref()
+            .or(self.encoding.as_ref())
+            .or(defaults.encoding.as_ref())
+            .cloned();
+
+        let locale = overwrites
+            .locale
+            .as_ref()
+            .or(self.locale.as_ref())
+            .or(defaults.locale.as_ref())
+            .cloned();
+
+        let network = overwrites
+            .network
+            .as_ref()
+            .or(self.network.as_ref())
+            .or(defaults.network.as_ref())
+            .cloned();
+
+        let parallel_seeds = overwrites
+            .parallel_seeds
+            .or(self.parallel_seeds)
+            .or(defaults.parallel_seeds);
+
+        let database = overwrites
+            .database
+            .as_ref()
+            .or(self.database.as_ref())
+            .or(defaults.database.as_ref())
+            .cloned()
+            .unwrap_or(pg_client::Database::POSTGRES);
+
+        let storage = overwrites
+            .storage
+            .or(self.storage)
+            .or(defaults.storage)
+            .unwrap_or_default();
+
+        let superuser = overwrites
+            .superuser
+            .as_ref()
+            .or(self.superuser.as_ref())
+            .or(defaults.superuser.as_ref())
+            .cloned()
+            .unwrap_or(pg_client::User::POSTGRES);
+
+        let seed_dependencies = self
+            .seeds
+            .iter()
+            .filter(|(_, seed_config)| !seed_config.after().is_empty())
+            .map(|(name, seed_config)| (name.clone(), seed_config.after().to_vec()))
+            .collect();
+
         let seeds = self
             .seeds
             .into_iter()
@@ -183,9 +614,8 @@ impl InstanceDefinition {
             .as_ref()
             .or(self.ssl_config.as_ref())
             .or(defaults.ssl_config.as_ref())
-            .map(|ssl_config_def| SslConfig::Generated {
-                hostname: ssl_config_def.hostname.clone(),
-            });
+            .cloned()
+            .map(SslConfig::from);
 
         let wait_available_timeout = overwrites
             .wait_available_timeout
@@ -193,26 +623,72 @@ impl InstanceDefinition {
             .or(defaults.wait_available_timeout)
             .unwrap_or(std::time::Duration::from_secs(10));
 
+        let connect_attempt_timeout = overwrites
+            .connect_attempt_timeout
+            .or(self.connect_attempt_timeout)
+            .or(defaults.connect_attempt_timeout);
+
+        let persist = overwrites
+            .persist
+            .or(self.persist)
+            .or(defaults.persist)
+            .unwrap_or(false);
+
         Ok(Instance {
-            application_name: None,
+            application_name,
             backend,
-            database: pg_client::Database::POSTGRES,
+            database,
+            additional_databases: self.additional_databases,
+            encoding,
+            extensions: self.extensions,
+            initdb_args: self.initdb_args,
+            keep_on_failure,
+            locale,
             seeds,
+            seed_dependencies,
+            server_parameters: self.server_parameters,
+            snapshot_cache,
             ssl_config,
-            superuser: pg_client::User::POSTGRES,
+            storage,
+            superuser,
             image,
-            cross_container_access: false,
+            cross_container_access,
+            host_port,
+            bind_address,
+            memory_limit,
+            cpu_limit,
+            network,
+            parallel_seeds,
+            persist,
+            connect_attempt_timeout,
             wait_available_timeout,
         })
     }
 }
 
-#[derive(Debug, serde::Deserialize, PartialEq)]
+#[derive(Clone, Debug, serde::Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
+    application_name: Option<pg_client::ApplicationName>,
     image: Option<Image>,
+    image_registry: Option<ociman::reference::Domain>,
+    image_repository: Option<ociman::reference::Path>,
     backend: Option<ociman::backend::Selection>,
+    cross_container_access: Option<bool>,
+    database: Option<pg_client::Database>,
+    encoding: Option<String>,
+    keep_on_failure: Option<bool>,
+    locale: Option<String>,
+    memory_limit: Option<MemoryLimit>,
+    #[serde(alias = "cpus")]
+    cpu_limit: Option<CpuLimit>,
+    network: Option<String>,
+    snapshot_cache: Option<bool>,
     ssl_config: Option<SslConfigDefinition>,
+    storage: Option<Storage>,
+    superuser: Option<pg_client::User>,
+    #[serde(default, with = "humantime_serde")]
+    connect_attempt_timeout: Option<std::time::Duration>,
     #[serde(default, with = "humantime_serde")]
     wait_available_timeout: Option<std::time::Duration>,
     instances: Option<std::collections::BTreeMap<InstanceName, InstanceDefinition>>,
@@ -221,9 +697,24 @@ pub struct Config {
 impl std::default::Default for Config {
     fn default() -> Self {
         Self {
+            application_name: None,
             image: Some(Image::default()),
+            image_registry: None,
+            image_repository: None,
             backend: None,
+            cross_container_access: None,
+            database: None,
+            encoding: None,
+            keep_on_failure: None,
+            locale: None,
+            memory_limit: None,
+            cpu_limit: None,
+            network: None,
+            snapshot_cache: None,
             ssl_config: None,
+            storage: None,
+            superuser: None,
+            connect_attempt_timeout: None,
             wait_available_timeout: None,
             instances: None,
         }
@@ -245,15 +736,65 @@ impl Config {
         toml::from_str(contents.as_ref()).map_err(Error::TomlDecode)
     }
 
+    /// Definition of the named instance, see [`Config::instance_map`]
+    pub fn definition_for(
+        &self,
+        instance_name: &InstanceName,
+        overwrites: &InstanceDefinition,
+    ) -> Result<Definition, Error> {
+        let instance_map = self.clone().instance_map(overwrites)?;
+
+        let instance = instance_map
+            .get(instance_name)
+            .ok_or_else(|| Error::UnknownInstance(instance_name.clone()))?;
+
+        Ok(instance.definition()?.instance_name(instance_name.clone()))
+    }
+
+    /// Definition of the only instance, erroring when the config defines more than one
+    pub fn single_definition(&self, overwrites: &InstanceDefinition) -> Result<Definition, Error> {
+        let instance_map = self.clone().instance_map(overwrites)?;
+
+        match instance_map.iter().collect::<Vec<_>>().as_slice() {
+            [(instance_name, instance)] => Ok(instance
+                .definition()?
+                .instance_name((*instance_name).clone())),
+            _ => Err(Error::NotSingleInstance(instance_map.into_keys().collect())),
+        }
+    }
+
     pub fn instance_map(
         self,
         overwrites: &InstanceDefinition,
     ) -> Result<super::InstanceMap, Error> {
         let defaults = InstanceDefinition {
+            application_name: self.application_name.clone(),
             backend: self.backend,
+            cross_container_access: self.cross_container_access,
+            database: self.database.clone(),
+            additional_databases: Vec::new(),
+            encoding: self.encoding.clone(),
+            extensions: Vec::new(),
+            host_port: None,
+            bind_address: None,
+            memory_limit: self.memory_limit.clone(),
+            cpu_limit: self.cpu_limit,
+            initdb_args: Vec::new(),
+            keep_on_failure: self.keep_on_failure,
+            locale: self.locale.clone(),
+            network: self.network.clone(),
             image: self.image.clone(),
+            image_registry: self.image_registry.clone(),
+            image_repository: self.image_repository.clone(),
+            parallel_seeds: None,
+            persist: None,
             seeds: indexmap::IndexMap::new(),
+            server_parameters: std::collections::BTreeMap::new(),
+            snapshot_cache: self.snapshot_cache,
             ssl_config: self.ssl_config.clone(),
+            storage: self.storage,
+            superuser: self.superuser.clone(),
+            connect_attempt_timeout: self.connect_attempt_timeout,
             wait_available_timeout: self.wait_available_timeout,
         };
 
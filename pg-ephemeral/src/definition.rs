@@ -1,25 +1,401 @@
 use crate::Container;
+use crate::ExternalContainer;
+use crate::RunningContainer;
 use crate::seed::{
-    Command, CommandCacheConfig, DuplicateSeedName, LoadError, LoadedSeed, LoadedSeeds, Seed,
-    SeedName,
+    ApplyError, Command, CommandCacheConfig, DuplicateSeedName, LoadError, LoadedSeed, LoadedSeeds,
+    RestoreFormat, Seed, SeedHooks, SeedName,
 };
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum SslConfig {
-    Generated { hostname: pg_client::HostName },
-    // UserProvided { ca_cert: PathBuf, server_cert: PathBuf, server_key: PathBuf },
+    Generated {
+        hostname: pg_client::HostName,
+    },
+    UserProvided {
+        hostname: pg_client::HostName,
+        ca_cert: std::path::PathBuf,
+        server_cert: std::path::PathBuf,
+        server_key: std::path::PathBuf,
+    },
+}
+
+impl SslConfig {
+    #[must_use]
+    pub fn hostname(&self) -> &pg_client::HostName {
+        match self {
+            Self::Generated { hostname } | Self::UserProvided { hostname, .. } => hostname,
+        }
+    }
+}
+
+/// Name of a postgres server parameter, passed as `-c <name>=<value>`
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct ServerParameterName(String);
+
+impl ServerParameterName {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ServerParameterName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ServerParameterNameError {
+    #[error("Server parameter name cannot be empty")]
+    Empty,
+    #[error("Server parameter name {0:?} may only contain ASCII letters, digits and underscores")]
+    InvalidCharacter(String),
+}
+
+impl std::str::FromStr for ServerParameterName {
+    type Err = ServerParameterNameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() {
+            Err(ServerParameterNameError::Empty)
+        } else if value
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '_')
+        {
+            Ok(Self(value.to_string()))
+        } else {
+            Err(ServerParameterNameError::InvalidCharacter(
+                value.to_string(),
+            ))
+        }
+    }
+}
+
+impl TryFrom<String> for ServerParameterName {
+    type Error = ServerParameterNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Memory limit of the container, in bytes or with a `b`, `k`, `m` or `g` suffix
+///
+/// Deserializes from an integer number of bytes or a string like `"512m"`.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+#[serde(try_from = "MemoryLimitValue")]
+pub struct MemoryLimit(String);
+
+impl MemoryLimit {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "Memory limit {0:?} must be a positive number of bytes, optionally suffixed with b, k, m or g"
+)]
+pub struct MemoryLimitError(String);
+
+impl std::str::FromStr for MemoryLimit {
+    type Err = MemoryLimitError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let digits = value
+            .strip_suffix(['b', 'k', 'm', 'g', 'B', 'K', 'M', 'G'])
+            .unwrap_or(value);
+
+        if !digits.is_empty()
+            && digits.chars().all(|character| character.is_ascii_digit())
+            && digits.chars().any(|character| character != '0')
+        {
+            Ok(Self(value.to_lowercase()))
+        } else {
+            Err(MemoryLimitError(value.to_string()))
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum MemoryLimitValue {
+    Bytes(u64),
+    String(String),
+}
+
+impl TryFrom<MemoryLimitValue> for MemoryLimit {
+    type Error = MemoryLimitError;
+
+    fn try_from(value: MemoryLimitValue) -> Result<Self, Self::Error> {
+        match value {
+            MemoryLimitValue::Bytes(bytes) => bytes.to_string().parse(),
+            MemoryLimitValue::String(string) => string.parse(),
+        }
+    }
+}
+
+/// CPU limit of the container in, possibly fractional, cores
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize)]
+#[serde(try_from = "f64")]
+pub struct CpuLimit(f64);
+
+impl CpuLimit {
+    #[must_use]
+    pub fn cores(self) -> f64 {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+#[error("CPU limit {0} must be a positive number of cores")]
+pub struct CpuLimitError(f64);
+
+impl TryFrom<f64> for CpuLimit {
+    type Error = CpuLimitError;
+
+    fn try_from(cores: f64) -> Result<Self, Self::Error> {
+        if cores.is_finite() && cores > 0.0 {
+            Ok(Self(cores))
+        } else {
+            Err(CpuLimitError(cores))
+        }
+    }
+}
+
+pub(crate) const DEFAULT_STOP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Readiness probe attempt bound applied when no `connect_attempt_timeout` is configured
+pub(crate) fn default_connect_attempt_timeout(
+    wait_available_timeout: std::time::Duration,
+) -> std::time::Duration {
+    wait_available_timeout / 4
+}
+
+/// How `wait_available` decides the container is ready
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ReadinessProbe {
+    /// A connection can be established
+    #[default]
+    Connect,
+    /// The query succeeds and returns exactly one row
+    Query(String),
+    /// `pg_isready` succeeds inside the container
+    PgIsReady,
+}
+
+/// Objects included in or excluded from a schema dump
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SchemaDumpFilter {
+    /// Dump only these tables (`--table`)
+    pub include_tables: Vec<pg_client::identifier::QualifiedName>,
+    /// Skip these tables (`--exclude-table`)
+    pub exclude_tables: Vec<pg_client::identifier::QualifiedName>,
+    /// Dump only these schemas (`--schema`)
+    pub schemas: Vec<pg_client::identifier::Schema>,
+}
+
+/// Lines removed from `pg_dump` output to produce a stable, diffable schema
+///
+/// All flags default to `false` and the role map defaults to empty, which returns the dump
+/// unchanged. Matching is line based, relying on `pg_dump` emitting each of these
+/// statements on a line of its own.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SchemaDumpOptions {
+    /// Drop `SET ...;` and `SELECT pg_catalog.set_config(...)` session setup statements
+    pub strip_settings: bool,
+    /// Drop `--` comment lines, including the per-object `Name: ...; Type: ...` headers
+    pub strip_comments: bool,
+    /// Drop `ALTER ... OWNER TO ...;` statements and `GRANT` / `REVOKE` privileges
+    pub strip_ownership: bool,
+    /// Pass `--no-owner --no-privileges` to `pg_dump`, implies `strip_ownership`
+    pub no_owner: bool,
+    /// Rename roles in ownership and privilege statements and in `Owner:` comment headers,
+    /// keyed by the role as `pg_dump` renders it
+    pub role_map: std::collections::BTreeMap<String, String>,
+}
+
+impl SchemaDumpOptions {
+    /// Additional `pg_dump` arguments
+    #[must_use]
+    pub fn arguments(&self) -> Vec<String> {
+        if self.no_owner {
+            vec!["--no-owner".to_string(), "--no-privileges".to_string()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    pub(crate) fn strips(&self, line: &str) -> bool {
+        (self.strip_settings
+            && (line.starts_with("SET ") || line.starts_with("SELECT pg_catalog.set_config(")))
+            || (self.strip_comments && line.starts_with("--"))
+            || ((self.strip_ownership || self.no_owner) && is_role_statement(line))
+    }
+
+    /// Apply the role map to a line naming roles, other lines are returned unchanged
+    pub(crate) fn remap_roles<'a>(&self, line: &'a str) -> std::borrow::Cow<'a, str> {
+        if self.role_map.is_empty()
+            || !(is_role_statement(line)
+                || line.starts_with("ALTER DEFAULT PRIVILEGES ")
+                || (line.starts_with("-- Name: ") && line.contains("; Owner: ")))
+        {
+            return std::borrow::Cow::Borrowed(line);
+        }
+
+        const MARKERS: [&str; 5] = [" OWNER TO ", " TO ", " FROM ", " FOR ROLE ", "; Owner: "];
+
+        let mut output = String::with_capacity(line.len());
+        let mut rest = line;
+
+        while let Some((index, marker)) = MARKERS
+            .iter()
+            .filter_map(|marker| rest.find(marker).map(|index| (index, marker)))
+            .min_by_key(|(index, _)| *index)
+        {
+            let (head, tail) = rest.split_at(index + marker.len());
+            output.push_str(head);
+            rest = tail;
+
+            // Role lists are `, ` separated and end at `;`, a space or the end of the line
+            loop {
+                let end = rest.find([',', ';', ' ']).unwrap_or(rest.len());
+                let (role, tail) = rest.split_at(end);
+
+                output.push_str(self.role_map.get(role).map_or(role, String::as_str));
+                rest = tail;
+
+                match rest.strip_prefix(", ") {
+                    Some(tail) => {
+                        output.push_str(", ");
+                        rest = tail;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        output.push_str(rest);
+
+        std::borrow::Cow::Owned(output)
+    }
+}
+
+/// `ALTER ... OWNER TO ...;`, `GRANT` and `REVOKE` statements
+fn is_role_statement(line: &str) -> bool {
+    (line.starts_with("ALTER ") && line.contains(" OWNER TO "))
+        || line.starts_with("GRANT ")
+        || line.starts_with("REVOKE ")
+}
+
+impl SchemaDumpFilter {
+    /// `pg_dump` arguments matching each name exactly, without pattern expansion
+    #[must_use]
+    pub fn arguments(&self) -> Vec<String> {
+        let table_pattern = |name: &pg_client::identifier::QualifiedName| {
+            format!(
+                "{}.{}",
+                quote_identifier(name.schema.as_str()),
+                quote_identifier(name.table.as_str())
+            )
+        };
+
+        let schemas = self
+            .schemas
+            .iter()
+            .map(|schema| format!("--schema={}", quote_identifier(schema.as_str())));
+
+        let include_tables = self
+            .include_tables
+            .iter()
+            .map(|name| format!("--table={}", table_pattern(name)));
+
+        let exclude_tables = self
+            .exclude_tables
+            .iter()
+            .map(|name| format!("--exclude-table={}", table_pattern(name)));
+
+        schemas
+            .chain(include_tables)
+            .chain(exclude_tables)
+            .collect()
+    }
+}
+
+/// How [`Container::reset`] returns the databases to their seeded state
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResetStrategy {
+    /// Empty every table and re-apply the seeds on top of the existing schema
+    ///
+    /// Cheaper than recreating, but only suits seeds that can run twice, such as
+    /// `CREATE TABLE IF NOT EXISTS` followed by inserts.
+    Truncate,
+    /// Drop and recreate the databases, then seed them like a fresh container
+    RecreateDatabase,
+}
+
+/// Where the container keeps `PGDATA`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Storage {
+    /// Container filesystem
+    #[default]
+    Disk,
+    /// In memory tmpfs mount, faster but lost when the container stops
+    Tmpfs,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Definition {
+    /// Databases created in addition to `database` before any seed is applied
+    pub additional_databases: Vec<pg_client::Database>,
     pub application_name: Option<pg_client::ApplicationName>,
     pub backend: ociman::Backend,
     pub database: pg_client::Database,
+    /// Encoding of the cluster created by initdb
+    pub encoding: Option<String>,
+    /// Extensions created before any seed is applied
+    pub extensions: Vec<pg_client::identifier::Extension>,
+    /// Additional initdb arguments, passed after `encoding` and `locale`
+    pub initdb_args: Vec<String>,
     pub seeds: indexmap::IndexMap<SeedName, Seed>,
+    /// Seeds that have to be applied before the keyed seed, seeds are otherwise applied
+    /// in insertion order
+    pub seed_dependencies: std::collections::BTreeMap<SeedName, Vec<SeedName>>,
+    /// Server parameters passed to postgres as `-c <name>=<value>`
+    pub server_parameters: std::collections::BTreeMap<ServerParameterName, String>,
+    /// Commit the seeded container as an image keyed by the seed fingerprint and reuse it
+    pub snapshot_cache: bool,
     pub ssl_config: Option<SslConfig>,
+    pub storage: Storage,
     pub superuser: pg_client::User,
     pub image: crate::image::Image,
+    /// Name of the instance, keys the snapshot, seed cache images and persisted container
+    pub instance_name: crate::InstanceName,
     pub cross_container_access: bool,
+    pub host_port: Option<pg_client::Port>,
+    /// Host address the container port is published on, overriding `cross_container_access`
+    pub bind_address: Option<std::net::IpAddr>,
+    pub memory_limit: Option<MemoryLimit>,
+    pub cpu_limit: Option<CpuLimit>,
+    /// Leave the container running when seeding or the action panics, for debugging
+    pub keep_on_failure: bool,
+    /// Locale of the cluster created by initdb
+    pub locale: Option<String>,
+    /// Network the container joins, reachable from other containers under a generated alias
+    pub network: Option<String>,
+    /// Apply consecutive SQL seeds concurrently with up to this many connections
+    pub parallel_seeds: Option<std::num::NonZeroU16>,
+    /// Keep the container running after the handle is dropped, named after `instance_name`
+    pub persist: bool,
+    pub readiness_probe: ReadinessProbe,
+    /// Grace period postgres gets to shut down before the container is killed
+    pub stop_timeout: std::time::Duration,
+    /// Bound on a single readiness probe attempt, see [`Definition::connect_attempt_timeout`]
+    pub connect_attempt_timeout: Option<std::time::Duration>,
     pub wait_available_timeout: std::time::Duration,
 }
 
@@ -28,13 +404,34 @@ impl Definition {
     pub fn new(backend: ociman::backend::Backend, image: crate::image::Image) -> Self {
         Self {
             backend,
+            additional_databases: Vec::new(),
             application_name: None,
             seeds: indexmap::IndexMap::new(),
+            seed_dependencies: std::collections::BTreeMap::new(),
+            server_parameters: std::collections::BTreeMap::new(),
+            snapshot_cache: false,
             ssl_config: None,
+            storage: Storage::Disk,
             superuser: pg_client::User::POSTGRES,
             database: pg_client::Database::POSTGRES,
+            encoding: None,
+            extensions: Vec::new(),
+            initdb_args: Vec::new(),
             image,
+            instance_name: crate::InstanceName::default(),
             cross_container_access: false,
+            host_port: None,
+            bind_address: None,
+            memory_limit: None,
+            cpu_limit: None,
+            keep_on_failure: false,
+            locale: None,
+            network: None,
+            parallel_seeds: None,
+            persist: false,
+            readiness_probe: ReadinessProbe::Connect,
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
+            connect_attempt_timeout: None,
             wait_available_timeout: std::time::Duration::from_secs(10),
         }
     }
@@ -50,24 +447,78 @@ impl Definition {
         Ok(Self { seeds, ..self })
     }
 
+    /// Apply the seed `name` only after all seeds in `after`
+    #[must_use]
+    pub fn seed_after(mut self, name: SeedName, after: impl IntoIterator<Item = SeedName>) -> Self {
+        self.seed_dependencies
+            .entry(name)
+            .or_default()
+            .extend(after);
+        self
+    }
+
     pub fn apply_file(
         self,
         name: SeedName,
         path: std::path::PathBuf,
     ) -> Result<Self, DuplicateSeedName> {
-        self.add_seed(name, Seed::SqlFile { path })
+        self.add_seed(
+            name,
+            Seed::SqlFile {
+                path,
+                database: None,
+            },
+        )
     }
 
-    pub fn load_seeds(&self, instance_name: &str) -> Result<LoadedSeeds<'_>, LoadError> {
-        LoadedSeeds::load(
-            &self.image,
-            self.ssl_config.as_ref(),
-            &self.seeds,
-            &self.backend,
-            instance_name,
+    pub fn apply_directory(
+        self,
+        name: SeedName,
+        path: std::path::PathBuf,
+        pattern: Option<String>,
+    ) -> Result<Self, DuplicateSeedName> {
+        self.add_seed(
+            name,
+            Seed::SqlDirectory {
+                path,
+                pattern,
+                database: None,
+            },
         )
     }
 
+    pub fn apply_restore(
+        self,
+        name: SeedName,
+        path: std::path::PathBuf,
+        format: RestoreFormat,
+    ) -> Result<Self, DuplicateSeedName> {
+        self.add_seed(
+            name,
+            Seed::Restore {
+                path,
+                format,
+                database: None,
+            },
+        )
+    }
+
+    pub fn load_seeds(&self, instance_name: &str) -> Result<LoadedSeeds<'_>, LoadError> {
+        for (name, seed) in &self.seeds {
+            if let Some(database) = seed.database()
+                && *database != self.database
+                && !self.additional_databases.contains(database)
+            {
+                return Err(LoadError::UnknownDatabase {
+                    name: name.clone(),
+                    database: database.clone(),
+                });
+            }
+        }
+
+        LoadedSeeds::load(self, instance_name)
+    }
+
     pub fn print_cache_status(&self, instance_name: &str, verbose: bool) {
         match self.load_seeds(instance_name) {
             Ok(loaded_seeds) => loaded_seeds.print(verbose),
@@ -75,6 +526,11 @@ impl Definition {
         }
     }
 
+    #[must_use]
+    pub fn storage(self, storage: Storage) -> Self {
+        Self { storage, ..self }
+    }
+
     #[must_use]
     pub fn superuser(self, user: pg_client::User) -> Self {
         Self {
@@ -83,6 +539,81 @@ impl Definition {
         }
     }
 
+    #[must_use]
+    pub fn database(self, database: pg_client::Database) -> Self {
+        Self { database, ..self }
+    }
+
+    #[must_use]
+    pub fn additional_database(mut self, database: pg_client::Database) -> Self {
+        if database != self.database && !self.additional_databases.contains(&database) {
+            self.additional_databases.push(database);
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn extension(mut self, extension: pg_client::identifier::Extension) -> Self {
+        if !self.extensions.contains(&extension) {
+            self.extensions.push(extension);
+        }
+        self
+    }
+
+    /// Encoding of the cluster, e.g. `UTF8`
+    ///
+    /// Only applies when `PGDATA` is initialized, not to containers started from snapshots.
+    #[must_use]
+    pub fn encoding(self, encoding: impl Into<String>) -> Self {
+        Self {
+            encoding: Some(encoding.into()),
+            ..self
+        }
+    }
+
+    /// Locale of the cluster, e.g. `C`
+    ///
+    /// Only applies when `PGDATA` is initialized, not to containers started from snapshots.
+    #[must_use]
+    pub fn locale(self, locale: impl Into<String>) -> Self {
+        Self {
+            locale: Some(locale.into()),
+            ..self
+        }
+    }
+
+    /// Pass an additional argument to initdb
+    #[must_use]
+    pub fn initdb_arg(mut self, argument: impl Into<String>) -> Self {
+        self.initdb_args.push(argument.into());
+        self
+    }
+
+    /// Value of `POSTGRES_INITDB_ARGS` for the official postgres images, `None` if no
+    /// initdb argument is configured
+    ///
+    /// The entrypoint splits the value on whitespace, arguments cannot contain spaces.
+    #[must_use]
+    pub fn postgres_initdb_args(&self) -> Option<String> {
+        let arguments: Vec<String> = self
+            .encoding
+            .iter()
+            .map(|encoding| format!("--encoding={encoding}"))
+            .chain(
+                self.locale
+                    .iter()
+                    .map(|locale| format!("--locale={locale}")),
+            )
+            .chain(self.initdb_args.iter().cloned())
+            .collect();
+
+        if arguments.is_empty() {
+            None
+        } else {
+            Some(arguments.join(" "))
+        }
+    }
+
     pub fn apply_file_from_git_revision(
         self,
         name: SeedName,
@@ -94,89 +625,485 @@ impl Definition {
             Seed::SqlFileGitRevision {
                 git_revision: git_revision.into(),
                 path,
+                database: None,
+            },
+        )
+    }
+
+    pub fn apply_command(
+        self,
+        name: SeedName,
+        command: Command,
+        cache: CommandCacheConfig,
+    ) -> Result<Self, DuplicateSeedName> {
+        self.add_seed(
+            name,
+            Seed::Command {
+                command,
+                cache,
+                database: None,
+            },
+        )
+    }
+
+    pub fn apply_script(
+        self,
+        name: SeedName,
+        script: impl Into<String>,
+    ) -> Result<Self, DuplicateSeedName> {
+        self.add_seed(
+            name,
+            Seed::Script {
+                script: script.into(),
+                database: None,
             },
         )
     }
 
-    pub fn apply_command(
-        self,
-        name: SeedName,
-        command: Command,
-        cache: CommandCacheConfig,
-    ) -> Result<Self, DuplicateSeedName> {
-        self.add_seed(name, Seed::Command { command, cache })
+    #[must_use]
+    pub fn server_parameter(mut self, name: ServerParameterName, value: impl Into<String>) -> Self {
+        self.server_parameters.insert(name, value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn ssl_config(self, ssl_config: SslConfig) -> Self {
+        Self {
+            ssl_config: Some(ssl_config),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn cross_container_access(self, enabled: bool) -> Self {
+        Self {
+            cross_container_access: enabled,
+            ..self
+        }
+    }
+
+    /// Leave the container running when seeding or the `with_container` action panics.
+    ///
+    /// The container ID and database URL are logged so a developer can attach to it, the
+    /// container has to be removed manually afterwards. Containers are still stopped and
+    /// removed on success.
+    #[must_use]
+    pub fn keep_on_failure(self, enabled: bool) -> Self {
+        Self {
+            keep_on_failure: enabled,
+            ..self
+        }
+    }
+
+    /// Keep the container running after the handle is dropped.
+    ///
+    /// The container is named `pg-ephemeral-<instance_name>` so later runs and other tools
+    /// can find it, it has to be stopped and removed manually. Starting the definition again
+    /// attaches to the running container without seeding it again. A stopped container of
+    /// that name has to be removed first.
+    #[must_use]
+    pub fn persist(self, enabled: bool) -> Self {
+        Self {
+            persist: enabled,
+            ..self
+        }
+    }
+
+    /// Name the snapshot, seed cache images and persisted container are named after, `main`
+    /// by default
+    #[must_use]
+    pub fn instance_name(self, instance_name: crate::InstanceName) -> Self {
+        Self {
+            instance_name,
+            ..self
+        }
+    }
+
+    /// Name of the container when `persist` is enabled
+    #[must_use]
+    pub fn persisted_container_name(&self) -> Option<String> {
+        self.persist
+            .then(|| format!("pg-ephemeral-{}", self.instance_name))
+    }
+
+    /// Reuse a committed image of the seeded container while the seed fingerprint matches.
+    ///
+    /// Has no effect when a seed is uncacheable or `PGDATA` is kept on tmpfs.
+    #[must_use]
+    pub fn snapshot_cache(self, enabled: bool) -> Self {
+        Self {
+            snapshot_cache: enabled,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn network(self, network: impl Into<String>) -> Self {
+        Self {
+            network: Some(network.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn host_port(self, host_port: pg_client::Port) -> Self {
+        Self {
+            host_port: Some(host_port),
+            ..self
+        }
+    }
+
+    /// Cap the memory available to the container, passed as `--memory`.
+    #[must_use]
+    pub fn memory_limit(self, limit: MemoryLimit) -> Self {
+        Self {
+            memory_limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Cap the CPU cores available to the container, passed as `--cpus`.
+    #[must_use]
+    pub fn cpu_limit(self, limit: CpuLimit) -> Self {
+        Self {
+            cpu_limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Publish the container port on `address`, e.g. a docker bridge address.
+    ///
+    /// Takes precedence over `cross_container_access`. Clients connect through `address`
+    /// unless it is unspecified, in which case they connect through localhost.
+    #[must_use]
+    pub fn bind_address(self, address: std::net::IpAddr) -> Self {
+        Self {
+            bind_address: Some(address),
+            ..self
+        }
+    }
+
+    /// Apply consecutive SQL seeds concurrently, using up to `jobs` connections.
+    ///
+    /// SQL seeds between two command or script seeds must not depend on each other.
+    /// Command and script seeds are always applied on their own, in declaration order.
+    #[must_use]
+    pub fn parallel_seeds(self, jobs: std::num::NonZeroU16) -> Self {
+        Self {
+            parallel_seeds: Some(jobs),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn readiness_probe(self, readiness_probe: ReadinessProbe) -> Self {
+        Self {
+            readiness_probe,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn stop_timeout(self, timeout: std::time::Duration) -> Self {
+        Self {
+            stop_timeout: timeout,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn wait_available_timeout(self, timeout: std::time::Duration) -> Self {
+        Self {
+            wait_available_timeout: timeout,
+            ..self
+        }
+    }
+
+    /// Bound each readiness probe attempt, so a stalled connect or TLS handshake counts as a
+    /// failed attempt and is retried instead of consuming the whole `wait_available_timeout`.
+    ///
+    /// Defaults to a quarter of `wait_available_timeout`.
+    #[must_use]
+    pub fn connect_attempt_timeout(self, timeout: std::time::Duration) -> Self {
+        Self {
+            connect_attempt_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn to_ociman_definition(&self) -> ociman::Definition {
+        self.ociman_definition((&self.image).into())
+    }
+
+    /// Render the backend `run` command a fresh container of this definition starts with,
+    /// without running anything.
+    ///
+    /// Includes the SSL entrypoint arguments, environment variables, publish settings and
+    /// mounts. The generated superuser password and server key are redacted.
+    #[must_use]
+    pub fn dry_run(&self) -> String {
+        crate::container::dry_run(self)
+    }
+
+    pub(crate) fn ociman_definition(
+        &self,
+        reference: ociman::image::Reference,
+    ) -> ociman::Definition {
+        let mut ociman_definition = ociman::Definition::new(self.backend.clone(), reference);
+
+        if let Some(network) = &self.network {
+            ociman_definition = ociman_definition.network(network.as_str());
+        }
+
+        if let Some(memory_limit) = &self.memory_limit {
+            ociman_definition = ociman_definition.memory(memory_limit.as_str());
+        }
+
+        if let Some(cpu_limit) = self.cpu_limit {
+            ociman_definition = ociman_definition.cpus(cpu_limit.cores().to_string());
+        }
+
+        ociman_definition
+    }
+
+    pub async fn with_container<T>(&self, action: impl AsyncFnMut(&Container) -> T) -> T {
+        self.with_container_hooks(&SeedHooks::default(), action)
+            .await
+    }
+
+    /// Like [`Definition::with_container`], invoking `hooks` around each applied seed.
+    pub async fn with_container_hooks<T>(
+        &self,
+        hooks: &SeedHooks<'_>,
+        mut action: impl AsyncFnMut(&Container) -> T,
+    ) -> T {
+        let mut db_container = self.start(hooks).await;
+
+        let result = self
+            .keep_on_panic(&mut db_container, async |db_container| {
+                action(db_container).await
+            })
+            .await;
+
+        if !self.persist {
+            db_container.stop_graceful();
+        }
+
+        result
+    }
+
+    /// Start and seed a container that keeps running until the returned handle is stopped
+    /// or dropped.
+    ///
+    /// Prints the [`crate::IntegrationServerHandshake`] to stdout, like
+    /// `run_integration_server`.
+    pub async fn start_detached(&self) -> RunningContainer {
+        let db_container = self.start(&SeedHooks::default()).await;
+
+        crate::IntegrationServerHandshake::new(db_container.client_config.clone()).print();
+
+        RunningContainer::new(db_container)
+    }
+
+    async fn start(&self, hooks: &SeedHooks<'_>) -> Container {
+        if let Some(db_container) = Container::attach_persisted(self) {
+            db_container.wait_available().await;
+
+            return db_container;
+        }
+
+        let loaded_seeds = self
+            .load_seeds(&self.instance_name.0)
+            .unwrap_or_else(|error| panic!("{error}"));
+
+        let snapshot_reference = self.snapshot_reference(&loaded_seeds);
+
+        match snapshot_reference {
+            Some(ref reference) if self.backend.is_image_present(reference) => {
+                log::info!("Starting from snapshot {reference}");
+
+                let mut db_container = Container::run_snapshot(self, reference);
+
+                self.keep_on_panic(&mut db_container, async |db_container| {
+                    db_container.wait_available().await
+                })
+                .await;
+
+                db_container
+            }
+            _ => {
+                let mut db_container = Container::run_definition(self);
+
+                self.keep_on_panic(&mut db_container, async |db_container| {
+                    db_container.wait_available().await;
+
+                    self.seed(db_container, &loaded_seeds, hooks).await;
+
+                    if let Some(ref reference) = snapshot_reference {
+                        log::info!("Committing snapshot {reference}");
+
+                        db_container.commit_snapshot(reference).await;
+                    }
+                })
+                .await;
+
+                db_container
+            }
+        }
+    }
+
+    /// Run `action` on the container, keeping the container for debugging if it panics and
+    /// `keep_on_failure` is enabled
+    async fn keep_on_panic<T>(
+        &self,
+        db_container: &mut Container,
+        action: impl AsyncFnOnce(&Container) -> T,
+    ) -> T {
+        match catch_unwind(action(db_container)).await {
+            Ok(value) => value,
+            Err(payload) => {
+                if self.keep_on_failure {
+                    db_container.keep();
+                }
+
+                std::panic::resume_unwind(payload)
+            }
+        }
     }
 
-    pub fn apply_script(
-        self,
-        name: SeedName,
-        script: impl Into<String>,
-    ) -> Result<Self, DuplicateSeedName> {
-        self.add_seed(
-            name,
-            Seed::Script {
-                script: script.into(),
-            },
-        )
+    /// The default database followed by the additional databases
+    fn databases(&self) -> impl Iterator<Item = &pg_client::Database> {
+        std::iter::once(&self.database).chain(&self.additional_databases)
     }
 
-    #[must_use]
-    pub fn ssl_config(self, ssl_config: SslConfig) -> Self {
-        Self {
-            ssl_config: Some(ssl_config),
-            ..self
+    /// Image the seeded container is committed to when snapshot caching applies
+    fn snapshot_reference(&self, loaded_seeds: &LoadedSeeds) -> Option<ociman::Reference> {
+        if !self.snapshot_cache {
+            return None;
         }
-    }
 
-    #[must_use]
-    pub fn cross_container_access(self, enabled: bool) -> Self {
-        Self {
-            cross_container_access: enabled,
-            ..self
+        if self.storage == Storage::Tmpfs {
+            log::warn!("Snapshot cache does not apply to tmpfs storage");
+            return None;
         }
+
+        loaded_seeds.fingerprint().map(|fingerprint| {
+            format!("pg-ephemeral/{}:{fingerprint}", self.instance_name)
+                .parse()
+                .unwrap()
+        })
     }
 
-    #[must_use]
-    pub fn wait_available_timeout(self, timeout: std::time::Duration) -> Self {
-        Self {
-            wait_available_timeout: timeout,
-            ..self
+    pub(crate) async fn seed(
+        &self,
+        target: &impl SeedTarget,
+        loaded_seeds: &LoadedSeeds<'_>,
+        hooks: &SeedHooks<'_>,
+    ) {
+        // External servers can be seeded repeatedly and already have the databases, CREATE
+        // DATABASE has no IF NOT EXISTS and cannot run inside a DO block.
+        for database in &self.additional_databases {
+            if !database_exists(target.client_config(), database).await {
+                crate::container::apply_sql(
+                    target.client_config(),
+                    &create_database_statement(database),
+                )
+                .await
+            }
         }
-    }
 
-    #[must_use]
-    pub fn to_ociman_definition(&self) -> ociman::Definition {
-        ociman::Definition::new(self.backend.clone(), (&self.image).into())
+        for extension in &self.extensions {
+            crate::container::apply_sql(
+                target.client_config(),
+                &create_extension_statement(extension),
+            )
+            .await
+        }
+
+        match self.parallel_seeds {
+            Some(jobs) => {
+                self.apply_loaded_seeds_parallel(target, loaded_seeds, jobs, hooks)
+                    .await
+            }
+            None => {
+                for loaded_seed in loaded_seeds.iter_seeds() {
+                    hooks
+                        .around(
+                            loaded_seed.name(),
+                            self.apply_loaded_seed(target, loaded_seed),
+                        )
+                        .await
+                }
+            }
+        }
     }
 
-    pub async fn with_container<T>(&self, mut action: impl AsyncFnMut(&Container) -> T) -> T {
+    /// Return the target's databases to their seeded state, see [`Container::reset`]
+    pub(crate) async fn reset(
+        &self,
+        target: &impl SeedTarget,
+        strategy: ResetStrategy,
+        hooks: &SeedHooks<'_>,
+    ) {
         let loaded_seeds = self
-            .load_seeds("main")
+            .load_seeds(&self.instance_name.0)
             .unwrap_or_else(|error| panic!("{error}"));
 
-        let mut db_container = Container::run_definition(self);
-
-        db_container.wait_available().await;
+        let databases = self.databases();
+
+        match strategy {
+            ResetStrategy::Truncate => {
+                for database in databases {
+                    crate::container::apply_sql(
+                        &crate::container::database_client_config(
+                            target.client_config(),
+                            Some(database),
+                        ),
+                        TRUNCATE_TABLES_STATEMENT,
+                    )
+                    .await
+                }
+            }
+            ResetStrategy::RecreateDatabase => {
+                let maintenance_config = crate::container::database_client_config(
+                    target.client_config(),
+                    Some(&MAINTENANCE_DATABASE),
+                );
+
+                for database in databases {
+                    crate::container::apply_sql(
+                        &maintenance_config,
+                        &drop_database_statement(database),
+                    )
+                    .await
+                }
 
-        for loaded_seed in loaded_seeds.iter_seeds() {
-            self.apply_loaded_seed(&db_container, loaded_seed).await
+                crate::container::apply_sql(
+                    &maintenance_config,
+                    &create_database_statement(&target.client_config().database),
+                )
+                .await
+            }
         }
 
-        let result = action(&db_container).await;
-
-        db_container.stop();
+        self.seed(target, &loaded_seeds, hooks).await
+    }
 
-        result
+    /// Attach to an already running server instead of managing a container
+    ///
+    /// The returned handle applies this definition's seeds and dumps the schema with the
+    /// local `pg_dump` / `pg_restore`, it never starts or stops a container.
+    #[must_use]
+    pub fn attach_external(&self, client_config: pg_client::Config) -> ExternalContainer<'_> {
+        ExternalContainer::new(self, client_config)
     }
 
     pub async fn run_integration_server(&self) {
         use tokio::io::AsyncReadExt;
 
         self.with_container(async |container| {
-            println!(
-                "{}",
-                serde_json::to_string(&container.client_config).unwrap()
-            );
+            crate::IntegrationServerHandshake::new(container.client_config.clone()).print();
             log::info!("Integration server is running waiting for EOF on stdin");
             let mut stdin = tokio::io::stdin();
             let mut buf = [0u8; 128];
@@ -199,45 +1126,112 @@ impl Definition {
         .await
     }
 
-    async fn apply_loaded_seed(&self, db_container: &Container, loaded_seed: &LoadedSeed) {
+    /// Apply consecutive SQL seeds concurrently, a seed depending on another seed of the
+    /// current batch starts the next batch
+    async fn apply_loaded_seeds_parallel(
+        &self,
+        target: &impl SeedTarget,
+        loaded_seeds: &LoadedSeeds<'_>,
+        jobs: std::num::NonZeroU16,
+        hooks: &SeedHooks<'_>,
+    ) {
+        let mut batch: Vec<(&SeedName, Vec<String>)> = Vec::new();
+
+        for loaded_seed in loaded_seeds.iter_seeds() {
+            match sql_statements(loaded_seed) {
+                Some(statements) => {
+                    let depends_on_batch = self
+                        .seed_dependencies
+                        .get(loaded_seed.name())
+                        .is_some_and(|dependencies| {
+                            batch.iter().any(|(name, _)| dependencies.contains(name))
+                        });
+
+                    if depends_on_batch {
+                        apply_sql_batch(target, std::mem::take(&mut batch), jobs, hooks).await;
+                    }
+
+                    batch.push((loaded_seed.name(), statements))
+                }
+                None => {
+                    apply_sql_batch(target, std::mem::take(&mut batch), jobs, hooks).await;
+                    hooks
+                        .around(
+                            loaded_seed.name(),
+                            self.apply_loaded_seed(target, loaded_seed),
+                        )
+                        .await
+                }
+            }
+        }
+
+        apply_sql_batch(target, batch, jobs, hooks).await
+    }
+
+    async fn apply_loaded_seed(&self, target: &impl SeedTarget, loaded_seed: &LoadedSeed) {
+        let client_config = crate::container::database_client_config(
+            target.client_config(),
+            loaded_seed.database(),
+        );
+
         match loaded_seed {
-            LoadedSeed::SqlFile { content, .. } => db_container.apply_sql(content).await,
-            LoadedSeed::SqlFileGitRevision { content, .. } => db_container.apply_sql(content).await,
-            LoadedSeed::Command { command, .. } => self.execute_command(db_container, command),
-            LoadedSeed::Script { script, .. } => self.execute_script(db_container, script),
+            LoadedSeed::SqlFile { content, .. } => {
+                crate::container::apply_sql(&client_config, content).await
+            }
+            LoadedSeed::SqlFileGitRevision { content, .. } => {
+                crate::container::apply_sql(&client_config, content).await
+            }
+            LoadedSeed::SqlDirectory { files, .. } => {
+                for file in files {
+                    crate::container::apply_sql(&client_config, &file.content).await
+                }
+            }
+            LoadedSeed::Restore {
+                format, content, ..
+            } => target.restore(*format, content, loaded_seed.database()),
+            LoadedSeed::Command { name, command, .. } => self
+                .execute_command(&client_config, command)
+                .map_err(|error| ApplyError::from_command_error(name, error))
+                .unwrap_or_else(|error| panic!("{error}")),
+            LoadedSeed::Script { name, script, .. } => self
+                .execute_script(&client_config, script)
+                .map_err(|error| ApplyError::from_command_error(name, error))
+                .unwrap_or_else(|error| panic!("{error}")),
         }
     }
 
-    fn execute_command(&self, db_container: &Container, command: &Command) {
-        cmd_proc::Command::new(&command.command)
-            .arguments(&command.arguments)
-            .envs(db_container.pg_env())
-            .env(&crate::ENV_DATABASE_URL, db_container.database_url())
-            .status()
-            .expect("Failed to execute command");
+    fn execute_command(
+        &self,
+        client_config: &pg_client::Config,
+        command: &Command,
+    ) -> Result<(), cmd_proc::CommandError> {
+        seed_command(client_config, command).status()
     }
 
-    fn execute_script(&self, db_container: &Container, script: &str) {
+    fn execute_script(
+        &self,
+        client_config: &pg_client::Config,
+        script: &str,
+    ) -> Result<(), cmd_proc::CommandError> {
         cmd_proc::Command::new("sh")
             .arguments(["-e", "-c"])
             .argument(script)
-            .envs(db_container.pg_env())
-            .env(&crate::ENV_DATABASE_URL, db_container.database_url())
+            .envs(client_config.to_pg_env())
+            .env(&crate::ENV_DATABASE_URL, client_config.to_url_string())
             .status()
-            .expect("Failed to execute script");
     }
 
     #[must_use]
     pub fn schema_dump(
         &self,
         client_config: &pg_client::Config,
+        filter: &SchemaDumpFilter,
         extra_arguments: &[String],
+        options: &SchemaDumpOptions,
     ) -> String {
         let (effective_config, mounts) = apply_ociman_mounts(client_config);
 
-        let mut effective_arguments = vec!["--schema-only".to_string()];
-
-        effective_arguments.extend_from_slice(extra_arguments);
+        let effective_arguments = schema_dump_arguments(filter, options, extra_arguments);
 
         let bytes = self
             .to_ociman_definition()
@@ -247,10 +1241,69 @@ impl Definition {
             .mounts(mounts)
             .run_capture_only_stdout();
 
-        crate::convert_schema(&bytes)
+        crate::convert_schema(&bytes, options)
     }
 }
 
+/// Server seeds are applied to, a managed container or an external server
+pub(crate) trait SeedTarget {
+    /// Client config connecting to the default database
+    fn client_config(&self) -> &pg_client::Config;
+
+    /// Restore a `pg_dump` archive into `database`, the default database when `None`
+    fn restore(
+        &self,
+        format: crate::seed::RestoreFormat,
+        archive: &[u8],
+        database: Option<&pg_client::Database>,
+    );
+}
+
+impl SeedTarget for Container {
+    fn client_config(&self) -> &pg_client::Config {
+        self.client_config()
+    }
+
+    fn restore(
+        &self,
+        format: crate::seed::RestoreFormat,
+        archive: &[u8],
+        database: Option<&pg_client::Database>,
+    ) {
+        self.exec_restore(format, archive, database)
+    }
+}
+
+pub(crate) fn seed_command(
+    client_config: &pg_client::Config,
+    command: &Command,
+) -> cmd_proc::Command {
+    let seed_command = cmd_proc::Command::new(&command.command)
+        .arguments(&command.arguments)
+        .envs(client_config.to_pg_env())
+        .env(&crate::ENV_DATABASE_URL, client_config.to_url_string())
+        .envs(command.env.iter().map(|(key, value)| (key.clone(), value)));
+
+    match &command.working_dir {
+        Some(working_dir) => seed_command.working_directory(working_dir),
+        None => seed_command,
+    }
+}
+
+pub(crate) fn schema_dump_arguments(
+    filter: &SchemaDumpFilter,
+    options: &SchemaDumpOptions,
+    extra_arguments: &[String],
+) -> Vec<String> {
+    let mut effective_arguments = vec!["--schema-only".to_string()];
+
+    effective_arguments.extend(options.arguments());
+    effective_arguments.extend(filter.arguments());
+    effective_arguments.extend_from_slice(extra_arguments);
+
+    effective_arguments
+}
+
 #[must_use]
 pub fn apply_ociman_mounts(
     client_config: &pg_client::Config,
@@ -288,6 +1341,122 @@ pub fn apply_ociman_mounts(
     }
 }
 
+/// Await `future`, returning the payload of a panic raised while polling it
+async fn catch_unwind<F: Future>(future: F) -> std::thread::Result<F::Output> {
+    let mut future = std::pin::pin!(future);
+
+    std::future::poll_fn(|context| {
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            future.as_mut().poll(context)
+        })) {
+            Ok(std::task::Poll::Ready(value)) => std::task::Poll::Ready(Ok(value)),
+            Ok(std::task::Poll::Pending) => std::task::Poll::Pending,
+            Err(payload) => std::task::Poll::Ready(Err(payload)),
+        }
+    })
+    .await
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn create_database_statement(database: &pg_client::Database) -> String {
+    format!("CREATE DATABASE {}", quote_identifier(database.as_str()))
+}
+
+async fn database_exists(
+    client_config: &pg_client::Config,
+    database: &pg_client::Database,
+) -> bool {
+    client_config
+        .with_sqlx_connection(async |connection| {
+            sqlx::query_scalar(
+                "SELECT EXISTS (SELECT FROM pg_catalog.pg_database WHERE datname = $1)",
+            )
+            .bind(database.as_str())
+            .fetch_one(connection)
+            .await
+            .unwrap()
+        })
+        .await
+        .unwrap()
+}
+
+fn drop_database_statement(database: &pg_client::Database) -> String {
+    format!(
+        "DROP DATABASE IF EXISTS {} WITH (FORCE)",
+        quote_identifier(database.as_str())
+    )
+}
+
+/// Database connected to while the seeded databases are dropped and recreated
+const MAINTENANCE_DATABASE: pg_client::Database =
+    pg_client::Database::from_static_or_panic("template1");
+
+/// Empty every table outside the system schemas, resetting sequences owned by them
+const TRUNCATE_TABLES_STATEMENT: &str = r"
+DO $$
+DECLARE
+    tables text;
+BEGIN
+    SELECT string_agg(format('%I.%I', schemaname, tablename), ', ')
+    INTO tables
+    FROM pg_catalog.pg_tables
+    WHERE schemaname NOT IN ('pg_catalog', 'information_schema');
+
+    IF tables IS NOT NULL THEN
+        EXECUTE 'TRUNCATE ' || tables || ' RESTART IDENTITY CASCADE';
+    END IF;
+END
+$$
+";
+
+fn create_extension_statement(extension: &pg_client::identifier::Extension) -> String {
+    format!(
+        "CREATE EXTENSION IF NOT EXISTS {}",
+        quote_identifier(extension.as_str())
+    )
+}
+
+/// Apply a batch of SQL seeds in parallel
+async fn apply_sql_batch(
+    target: &impl SeedTarget,
+    batch: Vec<(&SeedName, Vec<String>)>,
+    jobs: std::num::NonZeroU16,
+    hooks: &SeedHooks<'_>,
+) {
+    if batch.is_empty() {
+        return;
+    }
+
+    let seeds = batch
+        .into_iter()
+        .map(|(name, statements)| (name.clone(), statements))
+        .collect();
+
+    crate::container::apply_sql_parallel(target.client_config(), seeds, jobs, hooks).await
+}
+
+/// SQL statements of a seed that can be applied on its own connection, in order
+///
+/// Seeds targeting another database are applied on their own, like command seeds.
+fn sql_statements(loaded_seed: &LoadedSeed) -> Option<Vec<String>> {
+    if loaded_seed.database().is_some() {
+        return None;
+    }
+
+    match loaded_seed {
+        LoadedSeed::SqlFile { content, .. } | LoadedSeed::SqlFileGitRevision { content, .. } => {
+            Some(vec![content.clone()])
+        }
+        LoadedSeed::SqlDirectory { files, .. } => {
+            Some(files.iter().map(|file| file.content.clone()).collect())
+        }
+        LoadedSeed::Restore { .. } | LoadedSeed::Command { .. } | LoadedSeed::Script { .. } => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -298,6 +1467,340 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_server_parameter_name() {
+        assert_eq!(
+            "shared_buffers"
+                .parse::<ServerParameterName>()
+                .unwrap()
+                .as_str(),
+            "shared_buffers"
+        );
+        assert_eq!(
+            "".parse::<ServerParameterName>(),
+            Err(ServerParameterNameError::Empty)
+        );
+        assert_eq!(
+            "work_mem=1;--".parse::<ServerParameterName>(),
+            Err(ServerParameterNameError::InvalidCharacter(
+                "work_mem=1;--".to_string()
+            ))
+        );
+        assert_eq!(
+            "-c".parse::<ServerParameterName>(),
+            Err(ServerParameterNameError::InvalidCharacter("-c".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        assert_eq!("512m".parse::<MemoryLimit>().unwrap().as_str(), "512m");
+        assert_eq!("2G".parse::<MemoryLimit>().unwrap().as_str(), "2g");
+        assert_eq!(
+            "268435456".parse::<MemoryLimit>().unwrap().as_str(),
+            "268435456"
+        );
+
+        for invalid in ["", "m", "0m", "1.5g", "512mb", "-1"] {
+            assert_eq!(
+                invalid.parse::<MemoryLimit>(),
+                Err(MemoryLimitError(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_cpu_limit() {
+        assert_eq!(CpuLimit::try_from(1.5).unwrap().cores(), 1.5);
+        assert_eq!(CpuLimit::try_from(0.0), Err(CpuLimitError(0.0)));
+        assert_eq!(CpuLimit::try_from(-1.0), Err(CpuLimitError(-1.0)));
+        assert!(CpuLimit::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_create_database_statement() {
+        assert_eq!(
+            create_database_statement(&"analytics".parse().unwrap()),
+            r#"CREATE DATABASE "analytics""#
+        );
+    }
+
+    #[test]
+    fn test_create_extension_statement() {
+        assert_eq!(
+            create_extension_statement(&"uuid-ossp".parse().unwrap()),
+            r#"CREATE EXTENSION IF NOT EXISTS "uuid-ossp""#
+        );
+        assert_eq!(
+            create_extension_statement(&r#"a"b"#.parse().unwrap()),
+            r#"CREATE EXTENSION IF NOT EXISTS "a""b""#
+        );
+    }
+
+    const RAW_SCHEMA_DUMP: &str = indoc::indoc! {"
+        --
+        -- PostgreSQL database dump
+        --
+
+        SET statement_timeout = 0;
+        SET client_encoding = 'UTF8';
+        SELECT pg_catalog.set_config('search_path', '', false);
+
+        --
+        -- Name: users; Type: TABLE; Schema: public; Owner: postgres
+        --
+
+        CREATE TABLE public.users (
+            id integer NOT NULL
+        );
+
+
+        ALTER TABLE public.users OWNER TO postgres;
+
+        GRANT SELECT ON TABLE public.users TO reader;
+
+        --
+        -- PostgreSQL database dump complete
+        --
+
+    "};
+
+    #[test]
+    fn test_convert_schema_default_options() {
+        assert_eq!(
+            crate::convert_schema(RAW_SCHEMA_DUMP.as_bytes(), &SchemaDumpOptions::default()),
+            RAW_SCHEMA_DUMP
+        );
+    }
+
+    #[test]
+    fn test_convert_schema_strip_settings_and_comments() {
+        let options = SchemaDumpOptions {
+            strip_settings: true,
+            strip_comments: true,
+            ..SchemaDumpOptions::default()
+        };
+
+        assert_eq!(
+            crate::convert_schema(RAW_SCHEMA_DUMP.as_bytes(), &options),
+            indoc::indoc! {"
+                CREATE TABLE public.users (
+                    id integer NOT NULL
+                );
+
+                ALTER TABLE public.users OWNER TO postgres;
+
+                GRANT SELECT ON TABLE public.users TO reader;
+
+            "}
+        );
+    }
+
+    #[test]
+    fn test_convert_schema_strip_all() {
+        let options = SchemaDumpOptions {
+            strip_settings: true,
+            strip_comments: true,
+            strip_ownership: true,
+            ..SchemaDumpOptions::default()
+        };
+
+        assert_eq!(
+            crate::convert_schema(RAW_SCHEMA_DUMP.as_bytes(), &options),
+            indoc::indoc! {"
+                CREATE TABLE public.users (
+                    id integer NOT NULL
+                );
+
+            "}
+        );
+    }
+
+    #[test]
+    fn test_convert_schema_no_owner() {
+        let options = SchemaDumpOptions {
+            no_owner: true,
+            ..SchemaDumpOptions::default()
+        };
+
+        let schema = crate::convert_schema(RAW_SCHEMA_DUMP.as_bytes(), &options);
+
+        assert!(!schema.contains("OWNER TO"));
+        assert!(!schema.contains("GRANT "));
+        assert!(schema.contains("CREATE TABLE public.users ("));
+        assert_eq!(
+            schema_dump_arguments(&SchemaDumpFilter::default(), &options, &[]),
+            ["--schema-only", "--no-owner", "--no-privileges"]
+        );
+    }
+
+    #[test]
+    fn test_convert_schema_role_map() {
+        let options = SchemaDumpOptions {
+            strip_settings: true,
+            role_map: std::collections::BTreeMap::from([
+                ("postgres".to_string(), "owner".to_string()),
+                ("reader".to_string(), "app_reader".to_string()),
+            ]),
+            ..SchemaDumpOptions::default()
+        };
+
+        assert_eq!(
+            crate::convert_schema(RAW_SCHEMA_DUMP.as_bytes(), &options),
+            indoc::indoc! {"
+                --
+                -- PostgreSQL database dump
+                --
+
+                --
+                -- Name: users; Type: TABLE; Schema: public; Owner: owner
+                --
+
+                CREATE TABLE public.users (
+                    id integer NOT NULL
+                );
+
+                ALTER TABLE public.users OWNER TO owner;
+
+                GRANT SELECT ON TABLE public.users TO app_reader;
+
+                --
+                -- PostgreSQL database dump complete
+                --
+
+            "}
+        );
+
+        assert_eq!(
+            options.remap_roles("REVOKE ALL ON SCHEMA public FROM postgres, reader;"),
+            "REVOKE ALL ON SCHEMA public FROM owner, app_reader;"
+        );
+        assert_eq!(
+            options.remap_roles("ALTER TABLE public.postgres RENAME TO postgres;"),
+            "ALTER TABLE public.postgres RENAME TO postgres;"
+        );
+    }
+
+    #[test]
+    fn test_seed_command() {
+        let client_config = pg_client::Config {
+            application_name: None,
+            database: pg_client::Database::POSTGRES,
+            endpoint: pg_client::Endpoint::SocketPath("/socket".into()),
+            password: None,
+            replication: None,
+            ssl_mode: pg_client::SslMode::Disable,
+            ssl_root_cert: None,
+            user: pg_client::User::POSTGRES,
+        };
+
+        let rails_env = cmd_proc::EnvVariableName::from_static_or_panic("RAILS_ENV");
+
+        let command = Command::new("bin/rails", ["db:migrate"])
+            .env(rails_env.clone(), "test")
+            .working_dir("app");
+
+        let expected = cmd_proc::Command::new("bin/rails")
+            .argument("db:migrate")
+            .envs(client_config.to_pg_env())
+            .env(&crate::ENV_DATABASE_URL, client_config.to_url_string())
+            .env(&rails_env, "test")
+            .working_directory("app");
+
+        seed_command(&client_config, &command).test_eq(&expected);
+    }
+
+    #[test]
+    fn test_schema_dump_arguments() {
+        let filter = SchemaDumpFilter {
+            include_tables: vec![
+                pg_client::identifier::QualifiedName::new(
+                    pg_client::identifier::Schema::PUBLIC,
+                    "users".parse().unwrap(),
+                ),
+                pg_client::identifier::QualifiedName::new(
+                    "billing".parse().unwrap(),
+                    "Invoice Lines".parse().unwrap(),
+                ),
+            ],
+            exclude_tables: vec![pg_client::identifier::QualifiedName::new(
+                pg_client::identifier::Schema::PUBLIC,
+                r#"odd"name"#.parse().unwrap(),
+            )],
+            schemas: vec![pg_client::identifier::Schema::PUBLIC],
+        };
+
+        assert_eq!(
+            schema_dump_arguments(
+                &filter,
+                &SchemaDumpOptions::default(),
+                &["--no-comments".to_string()]
+            ),
+            [
+                "--schema-only",
+                r#"--schema="public""#,
+                r#"--table="public"."users""#,
+                r#"--table="billing"."Invoice Lines""#,
+                r#"--exclude-table="public"."odd""name""#,
+                "--no-comments",
+            ]
+        );
+
+        assert_eq!(
+            schema_dump_arguments(
+                &SchemaDumpFilter::default(),
+                &SchemaDumpOptions::default(),
+                &[]
+            ),
+            ["--schema-only"]
+        );
+    }
+
+    #[test]
+    fn test_to_ociman_definition_network() {
+        let image = crate::Image::default();
+
+        assert_eq!(
+            Definition::new(test_backend(), image.clone()).to_ociman_definition(),
+            ociman::Definition::new(test_backend(), (&image).into())
+        );
+
+        assert_eq!(
+            Definition::new(test_backend(), image.clone())
+                .network("app-net")
+                .to_ociman_definition(),
+            ociman::Definition::new(test_backend(), (&image).into()).network("app-net")
+        );
+    }
+
+    #[test]
+    fn test_dry_run_ssl() {
+        let definition = Definition::new(test_backend(), crate::Image::default())
+            .host_port(pg_client::Port::new(15432))
+            .ssl_config(SslConfig::Generated {
+                hostname: "postgresql.example.com".parse().unwrap(),
+            });
+
+        let command = definition.dry_run();
+
+        assert!(command.starts_with("podman run --detach "), "{command}");
+        assert!(command.contains(" --ssl=on "), "{command}");
+        assert!(
+            command.contains(" --publish 127.0.0.1:15432:5432/tcp "),
+            "{command}"
+        );
+        assert!(
+            command.contains(" --env 'POSTGRES_PASSWORD=*****' "),
+            "{command}"
+        );
+        assert_eq!(
+            command.matches("POSTGRES_PASSWORD=").count(),
+            1,
+            "{command}"
+        );
+        assert!(!command.contains("PRIVATE KEY"), "{command}");
+    }
+
     #[test]
     fn test_add_seed_rejects_duplicate() {
         let definition = Definition::new(test_backend(), crate::Image::default());
@@ -308,6 +1811,7 @@ mod test {
                 seed_name.clone(),
                 Seed::SqlFile {
                     path: "file1.sql".into(),
+                    database: None,
                 },
             )
             .unwrap();
@@ -316,6 +1820,7 @@ mod test {
             seed_name.clone(),
             Seed::SqlFile {
                 path: "file2.sql".into(),
+                database: None,
             },
         );
 
@@ -331,6 +1836,7 @@ mod test {
                 "seed1".parse().unwrap(),
                 Seed::SqlFile {
                     path: "file1.sql".into(),
+                    database: None,
                 },
             )
             .unwrap();
@@ -339,6 +1845,7 @@ mod test {
             "seed2".parse().unwrap(),
             Seed::SqlFile {
                 path: "file2.sql".into(),
+                database: None,
             },
         );
 
@@ -359,6 +1866,20 @@ mod test {
         assert_eq!(result, Err(DuplicateSeedName(seed_name)));
     }
 
+    #[test]
+    fn test_apply_directory_rejects_duplicate() {
+        let definition = Definition::new(test_backend(), crate::Image::default());
+        let seed_name: SeedName = "test-seed".parse().unwrap();
+
+        let definition = definition
+            .apply_file(seed_name.clone(), "file1.sql".into())
+            .unwrap();
+
+        let result = definition.apply_directory(seed_name.clone(), "schema".into(), None);
+
+        assert_eq!(result, Err(DuplicateSeedName(seed_name)));
+    }
+
     #[test]
     fn test_apply_command_adds_seed() {
         let definition = Definition::new(test_backend(), crate::Image::default());
@@ -420,4 +1941,35 @@ mod test {
 
         assert_eq!(result, Err(DuplicateSeedName(seed_name)));
     }
+
+    #[test]
+    fn test_load_seeds_rejects_unknown_database() {
+        let seed_name: SeedName = "events".parse().unwrap();
+        let database: pg_client::Database = "analytics".parse().unwrap();
+
+        let definition = Definition::new(test_backend(), crate::Image::default())
+            .add_seed(
+                seed_name.clone(),
+                Seed::Script {
+                    script: "true".to_string(),
+                    database: Some(database.clone()),
+                },
+            )
+            .unwrap();
+
+        match definition.load_seeds("main") {
+            Err(LoadError::UnknownDatabase {
+                name,
+                database: actual,
+            }) => {
+                assert_eq!(name, seed_name);
+                assert_eq!(actual, database);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let definition = definition.additional_database(database);
+
+        assert!(definition.load_seeds("main").is_ok());
+    }
 }
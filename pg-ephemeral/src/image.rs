@@ -1,4 +1,13 @@
+/// Registry official postgres images are pulled from, unless overridden
+pub const DEFAULT_REGISTRY: &str = "registry.hub.docker.com";
+
+/// Repository official postgres images are pulled from, unless overridden
+pub const DEFAULT_REPOSITORY: &str = "library/postgres";
+
 /// Postgresql images supported, references images from <https://hub.docker.com/_/postgres>
+///
+/// Official images can be pulled from a mirror via [`Image::registry`] and
+/// [`Image::repository`], the tag stays the same.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Image {
     /// Official release
@@ -7,6 +16,8 @@ pub enum Image {
         minor: Minor,
         os: OS,
         digest: Option<Digest>,
+        registry: Option<ociman::reference::Domain>,
+        repository: Option<ociman::reference::Path>,
     },
     /// OfficialRelease candidate
     OfficialReleaseCandidate {
@@ -14,13 +25,20 @@ pub enum Image {
         number: ReleaseCandidateNumber,
         os: OS,
         digest: Option<Digest>,
+        registry: Option<ociman::reference::Domain>,
+        repository: Option<ociman::reference::Path>,
     },
     /// Latest image on docker.com
     ///
     /// Only use that one for quick and dirty testing, it's recommended to always pin
     /// specific images in config files. Also note that pg-ephemeral currently never refreshes
     /// `latest` once cached in the local registry it's never refreshed.
-    OfficialLatest { os: OS, digest: Option<Digest> },
+    OfficialLatest {
+        os: OS,
+        digest: Option<Digest>,
+        registry: Option<ociman::reference::Domain>,
+        repository: Option<ociman::reference::Path>,
+    },
     /// Explicit OCI image reference, bypassing the official postgres image naming
     Explicit(ociman::image::Reference),
 }
@@ -30,6 +48,112 @@ impl std::default::Default for Image {
         Self::OfficialLatest {
             os: OS::Default,
             digest: None,
+            registry: None,
+            repository: None,
+        }
+    }
+}
+
+impl Image {
+    /// Pull the image from `registry` instead of [`DEFAULT_REGISTRY`]
+    ///
+    /// [`Image::Explicit`] references are returned unchanged.
+    #[must_use]
+    pub fn registry(self, registry: ociman::reference::Domain) -> Self {
+        match self {
+            Self::OfficialRelease {
+                major,
+                minor,
+                os,
+                digest,
+                repository,
+                ..
+            } => Self::OfficialRelease {
+                major,
+                minor,
+                os,
+                digest,
+                registry: Some(registry),
+                repository,
+            },
+            Self::OfficialReleaseCandidate {
+                major,
+                number,
+                os,
+                digest,
+                repository,
+                ..
+            } => Self::OfficialReleaseCandidate {
+                major,
+                number,
+                os,
+                digest,
+                registry: Some(registry),
+                repository,
+            },
+            Self::OfficialLatest {
+                os,
+                digest,
+                repository,
+                ..
+            } => Self::OfficialLatest {
+                os,
+                digest,
+                registry: Some(registry),
+                repository,
+            },
+            Self::Explicit(reference) => Self::Explicit(reference),
+        }
+    }
+
+    /// Pull the image from `repository` instead of [`DEFAULT_REPOSITORY`]
+    ///
+    /// [`Image::Explicit`] references are returned unchanged.
+    #[must_use]
+    pub fn repository(self, repository: ociman::reference::Path) -> Self {
+        match self {
+            Self::OfficialRelease {
+                major,
+                minor,
+                os,
+                digest,
+                registry,
+                ..
+            } => Self::OfficialRelease {
+                major,
+                minor,
+                os,
+                digest,
+                registry,
+                repository: Some(repository),
+            },
+            Self::OfficialReleaseCandidate {
+                major,
+                number,
+                os,
+                digest,
+                registry,
+                ..
+            } => Self::OfficialReleaseCandidate {
+                major,
+                number,
+                os,
+                digest,
+                registry,
+                repository: Some(repository),
+            },
+            Self::OfficialLatest {
+                os,
+                digest,
+                registry,
+                ..
+            } => Self::OfficialLatest {
+                os,
+                digest,
+                registry,
+                repository: Some(repository),
+            },
+            Self::Explicit(reference) => Self::Explicit(reference),
         }
     }
 }
@@ -42,6 +166,7 @@ impl std::fmt::Display for Image {
                 minor,
                 os,
                 digest,
+                ..
             } => {
                 write!(formatter, "{major}{minor}{os}")?;
                 if let Some(digest) = digest {
@@ -54,6 +179,7 @@ impl std::fmt::Display for Image {
                 number,
                 os,
                 digest,
+                ..
             } => {
                 write!(formatter, "{major}rc{number}{os}")?;
                 if let Some(digest) = digest {
@@ -61,7 +187,7 @@ impl std::fmt::Display for Image {
                 }
                 Ok(())
             }
-            Self::OfficialLatest { os, digest } => {
+            Self::OfficialLatest { os, digest, .. } => {
                 match os {
                     OS::Default => write!(formatter, "latest")?,
                     OS::Explicit(value) => write!(formatter, "{value}")?,
@@ -141,6 +267,8 @@ impl std::str::FromStr for Image {
                 (tag("latest"), opt(digest)).map(|(_, digest)| Image::OfficialLatest {
                     os: OS::Default,
                     digest,
+                    registry: None,
+                    repository: None,
                 }),
             )
             .parse(input)
@@ -152,6 +280,8 @@ impl std::str::FromStr for Image {
                 (os_name, opt(digest)).map(|(os, digest)| Image::OfficialLatest {
                     os: OS::Explicit(os.to_string()),
                     digest,
+                    registry: None,
+                    repository: None,
                 }),
             )
             .parse(input)
@@ -179,6 +309,8 @@ impl std::str::FromStr for Image {
                             number,
                             os: os.unwrap_or(OS::Default),
                             digest,
+                            registry: None,
+                            repository: None,
                         }
                     }),
             )
@@ -202,6 +334,8 @@ impl std::str::FromStr for Image {
                         minor: minor.unwrap_or(Minor::Latest),
                         os: os.unwrap_or(OS::Default),
                         digest,
+                        registry: None,
+                        repository: None,
                     }),
             )
             .parse(input)
@@ -233,16 +367,34 @@ impl<'de> serde::Deserialize<'de> for Image {
 /// ```
 impl From<&Image> for ociman::image::Reference {
     fn from(image: &Image) -> Self {
-        match image {
-            Image::Explicit(reference) => reference.clone(),
-            Image::OfficialRelease { .. }
-            | Image::OfficialReleaseCandidate { .. }
-            | Image::OfficialLatest { .. } => {
-                format!("registry.hub.docker.com/library/postgres:{image}")
-                    .parse()
-                    .unwrap()
+        let (registry, repository) = match image {
+            Image::Explicit(reference) => return reference.clone(),
+            Image::OfficialRelease {
+                registry,
+                repository,
+                ..
             }
-        }
+            | Image::OfficialReleaseCandidate {
+                registry,
+                repository,
+                ..
+            }
+            | Image::OfficialLatest {
+                registry,
+                repository,
+                ..
+            } => (registry, repository),
+        };
+
+        let registry = registry
+            .as_ref()
+            .map_or_else(|| DEFAULT_REGISTRY.to_string(), ToString::to_string);
+
+        let repository = repository
+            .as_ref()
+            .map_or_else(|| DEFAULT_REPOSITORY.to_string(), ToString::to_string);
+
+        format!("{registry}/{repository}:{image}").parse().unwrap()
     }
 }
 
@@ -330,6 +482,8 @@ mod test {
             &Image::OfficialLatest {
                 os: OS::Default,
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -338,6 +492,8 @@ mod test {
             &Image::OfficialLatest {
                 os: OS::Explicit("trixie".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -348,6 +504,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Default,
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -358,6 +516,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Explicit("trixie".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -368,6 +528,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Explicit("bookworm".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -378,6 +540,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Explicit("alpine3.22".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -388,6 +552,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Explicit("alpine3.21".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -398,6 +564,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Explicit("alpine".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -408,6 +576,8 @@ mod test {
                 minor: Minor::Latest,
                 os: OS::Default,
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -418,6 +588,8 @@ mod test {
                 minor: Minor::Latest,
                 os: OS::Explicit("trixie".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -428,6 +600,8 @@ mod test {
                 minor: Minor::Explicit(6),
                 os: OS::Default,
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
 
@@ -438,6 +612,8 @@ mod test {
                 minor: Minor::Explicit(6),
                 os: OS::Explicit("trixie".to_string()),
                 digest: None,
+                registry: None,
+                repository: None,
             },
         );
     }
@@ -460,6 +636,8 @@ mod test {
                 minor: Minor::Explicit(6),
                 os: OS::Default,
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
 
@@ -470,6 +648,8 @@ mod test {
                 minor: Minor::Explicit(6),
                 os: OS::Explicit("trixie".to_string()),
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
 
@@ -480,6 +660,8 @@ mod test {
                 minor: Minor::Latest,
                 os: OS::Default,
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
 
@@ -491,6 +673,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Default,
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
 
@@ -501,6 +685,8 @@ mod test {
                 number: ReleaseCandidateNumber(1u8.try_into().unwrap()),
                 os: OS::Explicit("alpine".to_string()),
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
 
@@ -510,6 +696,8 @@ mod test {
             &Image::OfficialLatest {
                 os: OS::Default,
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
 
@@ -518,6 +706,8 @@ mod test {
             &Image::OfficialLatest {
                 os: OS::Explicit("trixie".to_string()),
                 digest: parsed_digest.clone(),
+                registry: None,
+                repository: None,
             },
         );
     }
@@ -530,6 +720,8 @@ mod test {
             minor: Minor::Explicit(6),
             os: OS::Default,
             digest: Some(Digest(hex::decode(hash).unwrap().try_into().unwrap())),
+            registry: None,
+            repository: None,
         };
 
         let reference: ociman::image::Reference = (&image).into();
@@ -538,6 +730,40 @@ mod test {
         assert_eq!(reference.to_string(), expected);
     }
 
+    #[test]
+    fn test_ociman_image_conversion_with_registry() {
+        let image: Image = "17.1".parse().unwrap();
+
+        let reference: ociman::image::Reference = (&image
+            .clone()
+            .registry("internal.registry".parse().unwrap())
+            .repository("mirror/postgres".parse().unwrap()))
+            .into();
+
+        assert_eq!(
+            reference.to_string(),
+            "internal.registry/mirror/postgres:17.1"
+        );
+
+        let reference: ociman::image::Reference =
+            (&image.registry("localhost:5000".parse().unwrap())).into();
+
+        assert_eq!(
+            reference.to_string(),
+            "localhost:5000/library/postgres:17.1"
+        );
+
+        let explicit = Image::Explicit("my-registry.com/postgres:17".parse().unwrap());
+
+        assert_eq!(
+            explicit
+                .clone()
+                .registry("internal.registry".parse().unwrap())
+                .repository("mirror/postgres".parse().unwrap()),
+            explicit
+        );
+    }
+
     #[test]
     fn test_parse_error_uppercase() {
         let error = "LATEST".parse::<Image>().unwrap_err();
@@ -4,17 +4,32 @@ pub mod config;
 pub mod container;
 pub mod definition;
 pub mod image;
+pub mod integration_server;
 pub mod seed;
 
 pub use config::{Config, Instance};
 pub use container::Container;
+pub use container::ExternalContainer;
+pub use container::RunningContainer;
+pub use definition::CpuLimit;
 pub use definition::Definition;
+pub use definition::MemoryLimit;
+pub use definition::ReadinessProbe;
+pub use definition::ResetStrategy;
+pub use definition::SchemaDumpFilter;
+pub use definition::SchemaDumpOptions;
+pub use definition::ServerParameterName;
+pub use definition::Storage;
 pub use image::Image;
+pub use integration_server::IntegrationServerHandshake;
+pub use seed::ApplyError;
 pub use seed::Command;
 pub use seed::CommandCacheConfig;
 pub use seed::DuplicateSeedName;
 pub use seed::LoadError;
+pub use seed::RestoreFormat;
 pub use seed::Seed;
+pub use seed::SeedHooks;
 pub use seed::SeedName;
 pub use seed::SeedNameError;
 
@@ -23,7 +38,6 @@ pub(crate) const LOCALHOST_IP: std::net::IpAddr =
     std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
 pub(crate) const UNSPECIFIED_IP: std::net::IpAddr =
     std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED);
-pub(crate) const LOCALHOST_HOST_ADDR: pg_client::HostAddr = pg_client::HostAddr::new(LOCALHOST_IP);
 pub(crate) const ENV_DATABASE_URL: cmd_proc::EnvVariableName =
     cmd_proc::EnvVariableName::from_static_or_panic("DATABASE_URL");
 
@@ -34,15 +48,59 @@ pub fn version() -> &'static semver::Version {
     &VERSION
 }
 
-pub(crate) fn convert_schema(value: &[u8]) -> String {
-    std::str::from_utf8(value)
-        .expect("schema contains invalid utf8")
-        .to_string()
+/// Convert raw `pg_dump` output into the schema text returned to callers.
+///
+/// The output is decoded as UTF-8 and, with the default options, returned verbatim.
+/// Each enabled [`SchemaDumpOptions`] flag drops the matching lines and the role map renames
+/// roles, after which runs of blank lines left behind are collapsed into a single blank line.
+pub(crate) fn convert_schema(value: &[u8], options: &SchemaDumpOptions) -> String {
+    let schema = std::str::from_utf8(value).expect("schema contains invalid utf8");
+
+    if *options == SchemaDumpOptions::default() {
+        return schema.to_string();
+    }
+
+    let mut output = String::with_capacity(schema.len());
+    let mut previous_blank = true;
+
+    for line in schema.lines().filter(|line| !options.strips(line)) {
+        let blank = line.trim().is_empty();
+
+        if !(blank && previous_blank) {
+            output.push_str(&options.remap_roles(line));
+            output.push('\n');
+        }
+
+        previous_blank = blank;
+    }
+
+    output
+}
+
+/// Returns the first character not allowed in instance and seed names.
+///
+/// Names end up in container names and cache keys, so only lowercase ASCII letters,
+/// digits and hyphens are allowed.
+pub(crate) fn invalid_name_character(value: &str) -> Option<char> {
+    value
+        .chars()
+        .find(|character| !matches!(character, 'a'..='z' | '0'..='9' | '-'))
 }
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Deserialize)]
+#[serde(try_from = "String")]
 pub struct InstanceName(pub String);
 
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InstanceNameError {
+    #[error("Instance name cannot be empty")]
+    Empty,
+    #[error(
+        "Instance name {name:?} contains {character:?}, only lowercase letters, digits and hyphens are allowed"
+    )]
+    InvalidCharacter { name: String, character: char },
+}
+
 impl std::default::Default for InstanceName {
     fn default() -> Self {
         Self("main".to_string())
@@ -50,10 +108,28 @@ impl std::default::Default for InstanceName {
 }
 
 impl std::str::FromStr for InstanceName {
-    type Err = std::convert::Infallible;
+    type Err = InstanceNameError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        Ok(Self(value.into()))
+        Self::try_from(value.to_string())
+    }
+}
+
+impl TryFrom<String> for InstanceName {
+    type Error = InstanceNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(InstanceNameError::Empty);
+        }
+
+        match invalid_name_character(&value) {
+            Some(character) => Err(InstanceNameError::InvalidCharacter {
+                name: value,
+                character,
+            }),
+            None => Ok(Self(value)),
+        }
     }
 }
 
@@ -64,3 +140,39 @@ impl std::fmt::Display for InstanceName {
 }
 
 pub type InstanceMap = std::collections::BTreeMap<InstanceName, config::Instance>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_instance_name_accepts_lowercase_digits_and_hyphens() {
+        assert_eq!(
+            "main".parse::<InstanceName>(),
+            Ok(InstanceName("main".to_string()))
+        );
+        assert_eq!(
+            "replica-2".parse::<InstanceName>(),
+            Ok(InstanceName("replica-2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_instance_name_rejects_invalid_names() {
+        assert_eq!("".parse::<InstanceName>(), Err(InstanceNameError::Empty));
+        assert_eq!(
+            "Bad Name".parse::<InstanceName>(),
+            Err(InstanceNameError::InvalidCharacter {
+                name: "Bad Name".to_string(),
+                character: 'B',
+            })
+        );
+        assert_eq!(
+            "a/b".parse::<InstanceName>(),
+            Err(InstanceNameError::InvalidCharacter {
+                name: "a/b".to_string(),
+                character: '/',
+            })
+        );
+    }
+}
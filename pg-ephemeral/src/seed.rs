@@ -50,7 +50,9 @@ impl CacheStatus {
     }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize,
+)]
 #[serde(try_from = "String")]
 pub struct SeedName(String);
 
@@ -68,8 +70,14 @@ impl std::fmt::Display for SeedName {
 }
 
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
-#[error("Seed name cannot be empty")]
-pub struct SeedNameError;
+pub enum SeedNameError {
+    #[error("Seed name cannot be empty")]
+    Empty,
+    #[error(
+        "Seed name {name:?} contains {character:?}, only lowercase letters, digits and hyphens are allowed"
+    )]
+    InvalidCharacter { name: String, character: char },
+}
 
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
 #[error("Duplicate seed name: {0}")]
@@ -79,11 +87,7 @@ impl std::str::FromStr for SeedName {
     type Err = SeedNameError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        if value.is_empty() {
-            Err(SeedNameError)
-        } else {
-            Ok(Self(value.to_string()))
-        }
+        Self::try_from(value.to_string())
     }
 }
 
@@ -92,9 +96,15 @@ impl TryFrom<String> for SeedName {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         if value.is_empty() {
-            Err(SeedNameError)
-        } else {
-            Ok(Self(value))
+            return Err(SeedNameError::Empty);
+        }
+
+        match crate::invalid_name_character(&value) {
+            Some(character) => Err(SeedNameError::InvalidCharacter {
+                name: value,
+                character,
+            }),
+            None => Ok(Self(value)),
         }
     }
 }
@@ -111,6 +121,10 @@ impl TryFrom<&str> for SeedName {
 pub struct Command {
     pub command: String,
     pub arguments: Vec<String>,
+    /// Extra environment variables, applied after the connection environment
+    pub env: std::collections::BTreeMap<cmd_proc::EnvVariableName<'static>, String>,
+    /// Working directory to run the command in, defaults to the current directory
+    pub working_dir: Option<std::path::PathBuf>,
 }
 
 impl Command {
@@ -121,8 +135,26 @@ impl Command {
         Self {
             command: command.into(),
             arguments: arguments.into_iter().map(|a| a.into()).collect(),
+            env: std::collections::BTreeMap::new(),
+            working_dir: None,
         }
     }
+
+    #[must_use]
+    pub fn env(
+        mut self,
+        key: cmd_proc::EnvVariableName<'static>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.env.insert(key, value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn working_dir(mut self, working_dir: impl Into<std::path::PathBuf>) -> Self {
+        self.working_dir = Some(working_dir.into());
+        self
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize, PartialEq)]
@@ -142,25 +174,78 @@ pub enum CommandCacheConfig {
     KeyScript { script: String },
 }
 
+/// Archive format passed to `pg_restore --format`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestoreFormat {
+    #[default]
+    Custom,
+    Tar,
+}
+
+impl RestoreFormat {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Custom => "custom",
+            Self::Tar => "tar",
+        }
+    }
+}
+
+/// Every variant takes an optional `database` the seed is applied to instead of the
+/// instance default, it must be the default or one of the additional databases.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Seed {
     SqlFile {
         path: std::path::PathBuf,
+        database: Option<pg_client::Database>,
     },
     SqlFileGitRevision {
         git_revision: String,
         path: std::path::PathBuf,
+        database: Option<pg_client::Database>,
+    },
+    /// Apply all files in a directory whose names match `pattern` (default `*.sql`),
+    /// in lexicographic file name order
+    SqlDirectory {
+        path: std::path::PathBuf,
+        pattern: Option<String>,
+        database: Option<pg_client::Database>,
+    },
+    /// Restore a `pg_dump` archive via `pg_restore --no-owner` inside the container.
+    ///
+    /// Plain SQL dumps are not archives, apply those with [`Seed::SqlFile`].
+    Restore {
+        path: std::path::PathBuf,
+        format: RestoreFormat,
+        database: Option<pg_client::Database>,
     },
     Command {
         command: Command,
         cache: CommandCacheConfig,
+        database: Option<pg_client::Database>,
     },
     Script {
         script: String,
+        database: Option<pg_client::Database>,
     },
 }
 
 impl Seed {
+    /// Database the seed is applied to, `None` for the instance default
+    #[must_use]
+    pub fn database(&self) -> Option<&pg_client::Database> {
+        match self {
+            Self::SqlFile { database, .. }
+            | Self::SqlFileGitRevision { database, .. }
+            | Self::SqlDirectory { database, .. }
+            | Self::Restore { database, .. }
+            | Self::Command { database, .. }
+            | Self::Script { database, .. } => database.as_ref(),
+        }
+    }
+
     fn load(
         &self,
         name: SeedName,
@@ -168,8 +253,15 @@ impl Seed {
         backend: &ociman::Backend,
         instance_name: &str,
     ) -> Result<LoadedSeed, LoadError> {
+        let database = self.database().cloned();
+
+        if let Some(ref database) = database {
+            hash_chain.update("database:");
+            hash_chain.update(database.as_str());
+        }
+
         match self {
-            Seed::SqlFile { path } => {
+            Seed::SqlFile { path, .. } => {
                 let content =
                     std::fs::read_to_string(path).map_err(|source| LoadError::FileRead {
                         name: name.clone(),
@@ -186,11 +278,14 @@ impl Seed {
                         instance_name,
                     ),
                     name,
+                    database,
                     path: path.clone(),
                     content,
                 })
             }
-            Seed::SqlFileGitRevision { path, git_revision } => {
+            Seed::SqlFileGitRevision {
+                path, git_revision, ..
+            } => {
                 let output =
                     git_proc::show::new(&format!("{git_revision}:{}", path.to_str().unwrap()))
                         .output()
@@ -220,6 +315,7 @@ impl Seed {
                             instance_name,
                         ),
                         name,
+                        database,
                         path: path.clone(),
                         git_revision: git_revision.clone(),
                         content,
@@ -241,7 +337,51 @@ impl Seed {
                     })
                 }
             }
-            Seed::Command { command, cache } => {
+            Seed::SqlDirectory { path, pattern, .. } => {
+                let pattern = pattern.as_deref().unwrap_or(DEFAULT_SQL_DIRECTORY_PATTERN);
+                let files = read_sql_directory(&name, path, pattern)?;
+
+                for file in &files {
+                    hash_chain.update(file.path.file_name().unwrap().as_encoded_bytes());
+                    hash_chain.update(&file.content);
+                }
+
+                Ok(LoadedSeed::SqlDirectory {
+                    cache_status: CacheStatus::from_cache_key(
+                        hash_chain.cache_key(),
+                        backend,
+                        instance_name,
+                    ),
+                    name,
+                    database,
+                    path: path.clone(),
+                    files,
+                })
+            }
+            Seed::Restore { path, format, .. } => {
+                let content = std::fs::read(path).map_err(|source| LoadError::FileRead {
+                    name: name.clone(),
+                    path: path.clone(),
+                    source,
+                })?;
+
+                hash_chain.update(format.as_str());
+                hash_chain.update(&content);
+
+                Ok(LoadedSeed::Restore {
+                    cache_status: CacheStatus::from_cache_key(
+                        hash_chain.cache_key(),
+                        backend,
+                        instance_name,
+                    ),
+                    name,
+                    database,
+                    path: path.clone(),
+                    format: *format,
+                    content,
+                })
+            }
+            Seed::Command { command, cache, .. } => {
                 let cache_key_output = match cache {
                     CommandCacheConfig::None => {
                         hash_chain.stop();
@@ -252,6 +392,16 @@ impl Seed {
                         for argument in &command.arguments {
                             hash_chain.update(argument);
                         }
+                        for (key, value) in &command.env {
+                            hash_chain.update("env:");
+                            hash_chain.update(key.as_str());
+                            hash_chain.update("=");
+                            hash_chain.update(value);
+                        }
+                        if let Some(working_dir) = &command.working_dir {
+                            hash_chain.update("working_dir:");
+                            hash_chain.update(working_dir.as_os_str().as_encoded_bytes());
+                        }
                         None
                     }
                     CommandCacheConfig::KeyCommand {
@@ -318,10 +468,11 @@ impl Seed {
                     ),
                     cache_key_output,
                     name,
+                    database,
                     command: command.clone(),
                 })
             }
-            Seed::Script { script } => {
+            Seed::Script { script, .. } => {
                 hash_chain.update(script);
 
                 Ok(LoadedSeed::Script {
@@ -331,6 +482,7 @@ impl Seed {
                         instance_name,
                     ),
                     name,
+                    database,
                     script: script.clone(),
                 })
             }
@@ -338,6 +490,73 @@ impl Seed {
     }
 }
 
+const DEFAULT_SQL_DIRECTORY_PATTERN: &str = "*.sql";
+
+fn read_sql_directory(
+    name: &SeedName,
+    path: &std::path::Path,
+    pattern: &str,
+) -> Result<Vec<SqlDirectoryFile>, LoadError> {
+    let directory_error = |source| LoadError::DirectoryRead {
+        name: name.clone(),
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let mut paths = Vec::new();
+
+    for entry in std::fs::read_dir(path).map_err(directory_error)? {
+        let entry = entry.map_err(directory_error)?;
+
+        if !entry.file_type().map_err(directory_error)?.is_file() {
+            continue;
+        }
+
+        let file_name = entry.file_name();
+
+        if file_name
+            .to_str()
+            .is_some_and(|file_name| matches_pattern(pattern, file_name))
+        {
+            paths.push(entry.path());
+        }
+    }
+
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(&path).map_err(|source| LoadError::FileRead {
+                name: name.clone(),
+                path: path.clone(),
+                source,
+            })?;
+
+            Ok(SqlDirectoryFile { path, content })
+        })
+        .collect()
+}
+
+/// Match a file name against a pattern supporting `*` and `?` wildcards
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    fn matches(pattern: &[u8], value: &[u8]) -> bool {
+        match (pattern.split_first(), value.split_first()) {
+            (None, None) => true,
+            (Some((b'*', rest)), _) => {
+                matches(rest, value) || (!value.is_empty() && matches(pattern, &value[1..]))
+            }
+            (Some((b'?', rest)), Some((_, value_rest))) => matches(rest, value_rest),
+            (Some((expected, rest)), Some((actual, value_rest))) => {
+                expected == actual && matches(rest, value_rest)
+            }
+            _ => false,
+        }
+    }
+
+    matches(pattern.as_bytes(), value.as_bytes())
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum LoadError {
     #[error("Failed to load seed {name}: could not read file {path}: {source}")]
@@ -346,6 +565,12 @@ pub enum LoadError {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
+    #[error("Failed to load seed {name}: could not read directory {path}: {source}")]
+    DirectoryRead {
+        name: SeedName,
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
     #[error(
         "Failed to load seed {name}: could not read {path} at git revision {git_revision}: {message}"
     )]
@@ -363,6 +588,66 @@ pub enum LoadError {
     },
     #[error("Failed to load seed {name}: cache key script failed: {message}")]
     KeyScript { name: SeedName, message: String },
+    #[error("Failed to load seeds: could not read SSL file {path}: {source}")]
+    SslFileRead {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to load seed {name}: database {database} is not created by the instance")]
+    UnknownDatabase {
+        name: SeedName,
+        database: pg_client::Database,
+    },
+    #[error("Failed to load seed {name}: depends on unknown seed {dependency}")]
+    UnknownDependency {
+        name: SeedName,
+        dependency: SeedName,
+    },
+    /// Every seed in `cycle` has to be applied after the next one, the last repeats the first
+    #[error(
+        "Failed to load seeds: dependency cycle {}",
+        .cycle.iter().map(ToString::to_string).collect::<Vec<_>>().join(" -> ")
+    )]
+    DependencyCycle { cycle: Vec<SeedName> },
+}
+
+/// Failure of a command or script seed while it is applied
+#[derive(Debug, thiserror::Error)]
+pub enum ApplyError {
+    #[error("Failed to apply seed {name}: could not execute: {source}")]
+    Execute {
+        name: SeedName,
+        source: std::io::Error,
+    },
+    #[error("Failed to apply seed {name}: exited with {status}")]
+    Exit {
+        name: SeedName,
+        status: std::process::ExitStatus,
+    },
+}
+
+impl ApplyError {
+    pub(crate) fn from_command_error(name: &SeedName, error: cmd_proc::CommandError) -> Self {
+        match error {
+            cmd_proc::CommandError {
+                exit_status: Some(status),
+                ..
+            } => Self::Exit {
+                name: name.clone(),
+                status,
+            },
+            cmd_proc::CommandError { io_error, .. } => Self::Execute {
+                name: name.clone(),
+                source: io_error.unwrap_or_else(|| std::io::Error::other("unknown failure")),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SqlDirectoryFile {
+    pub path: std::path::PathBuf,
+    pub content: String,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -370,25 +655,44 @@ pub enum LoadedSeed {
     SqlFile {
         cache_status: CacheStatus,
         name: SeedName,
+        database: Option<pg_client::Database>,
         path: std::path::PathBuf,
         content: String,
     },
     SqlFileGitRevision {
         cache_status: CacheStatus,
         name: SeedName,
+        database: Option<pg_client::Database>,
         path: std::path::PathBuf,
         git_revision: String,
         content: String,
     },
+    SqlDirectory {
+        cache_status: CacheStatus,
+        name: SeedName,
+        database: Option<pg_client::Database>,
+        path: std::path::PathBuf,
+        files: Vec<SqlDirectoryFile>,
+    },
+    Restore {
+        cache_status: CacheStatus,
+        name: SeedName,
+        database: Option<pg_client::Database>,
+        path: std::path::PathBuf,
+        format: RestoreFormat,
+        content: Vec<u8>,
+    },
     Command {
         cache_status: CacheStatus,
         cache_key_output: Option<Vec<u8>>,
         name: SeedName,
+        database: Option<pg_client::Database>,
         command: Command,
     },
     Script {
         cache_status: CacheStatus,
         name: SeedName,
+        database: Option<pg_client::Database>,
         script: String,
     },
 }
@@ -399,6 +703,8 @@ impl LoadedSeed {
         match self {
             Self::SqlFile { cache_status, .. } => cache_status,
             Self::SqlFileGitRevision { cache_status, .. } => cache_status,
+            Self::SqlDirectory { cache_status, .. } => cache_status,
+            Self::Restore { cache_status, .. } => cache_status,
             Self::Command { cache_status, .. } => cache_status,
             Self::Script { cache_status, .. } => cache_status,
         }
@@ -409,15 +715,32 @@ impl LoadedSeed {
         match self {
             Self::SqlFile { name, .. } => name,
             Self::SqlFileGitRevision { name, .. } => name,
+            Self::SqlDirectory { name, .. } => name,
+            Self::Restore { name, .. } => name,
             Self::Command { name, .. } => name,
             Self::Script { name, .. } => name,
         }
     }
 
+    /// Database the seed is applied to, `None` for the instance default
+    #[must_use]
+    pub fn database(&self) -> Option<&pg_client::Database> {
+        match self {
+            Self::SqlFile { database, .. }
+            | Self::SqlFileGitRevision { database, .. }
+            | Self::SqlDirectory { database, .. }
+            | Self::Restore { database, .. }
+            | Self::Command { database, .. }
+            | Self::Script { database, .. } => database.as_ref(),
+        }
+    }
+
     fn variant_name(&self) -> &'static str {
         match self {
             Self::SqlFile { .. } => "sql-file",
             Self::SqlFileGitRevision { .. } => "sql-file-git-revision",
+            Self::SqlDirectory { .. } => "sql-directory",
+            Self::Restore { .. } => "restore",
             Self::Command { .. } => "command",
             Self::Script { .. } => "script",
         }
@@ -499,40 +822,82 @@ impl HashChain {
 pub struct LoadedSeeds<'a> {
     image: &'a crate::image::Image,
     seeds: Vec<LoadedSeed>,
+    fingerprint: Option<CacheKey>,
 }
 
 impl<'a> LoadedSeeds<'a> {
     pub fn load(
-        image: &'a crate::image::Image,
-        ssl_config: Option<&crate::definition::SslConfig>,
-        seeds: &indexmap::IndexMap<SeedName, Seed>,
-        backend: &ociman::Backend,
+        definition: &'a crate::definition::Definition,
         instance_name: &str,
     ) -> Result<Self, LoadError> {
+        let image = &definition.image;
         let mut hash_chain = HashChain::new();
         let mut loaded_seeds = Vec::new();
 
         hash_chain.update(crate::VERSION_STR);
-        hash_chain.update(image.to_string());
+        hash_chain.update(ociman::image::Reference::from(image).to_string());
+        hash_chain.update("superuser:");
+        hash_chain.update(definition.superuser.as_str());
+        hash_chain.update("database:");
+        hash_chain.update(definition.database.as_str());
 
-        match ssl_config {
+        match &definition.ssl_config {
             Some(crate::definition::SslConfig::Generated { hostname }) => {
                 hash_chain.update("ssl:generated:");
                 hash_chain.update(hostname.as_str());
             }
+            Some(crate::definition::SslConfig::UserProvided {
+                hostname,
+                ca_cert,
+                server_cert,
+                server_key,
+            }) => {
+                hash_chain.update("ssl:user-provided:");
+                hash_chain.update(hostname.as_str());
+
+                for path in [ca_cert, server_cert, server_key] {
+                    let contents =
+                        std::fs::read(path).map_err(|source| LoadError::SslFileRead {
+                            path: path.clone(),
+                            source,
+                        })?;
+                    hash_chain.update(contents);
+                }
+            }
             None => {
                 hash_chain.update("ssl:none");
             }
         }
 
-        for (name, seed) in seeds {
-            let loaded_seed = seed.load(name.clone(), &mut hash_chain, backend, instance_name)?;
+        for extension in &definition.extensions {
+            hash_chain.update("extension:");
+            hash_chain.update(extension.as_str());
+        }
+
+        for database in &definition.additional_databases {
+            hash_chain.update("database:");
+            hash_chain.update(database.as_str());
+        }
+
+        if let Some(initdb_args) = definition.postgres_initdb_args() {
+            hash_chain.update("initdb:");
+            hash_chain.update(initdb_args);
+        }
+
+        for (name, seed) in ordered_seeds(definition)? {
+            let loaded_seed = seed.load(
+                name.clone(),
+                &mut hash_chain,
+                &definition.backend,
+                instance_name,
+            )?;
             loaded_seeds.push(loaded_seed);
         }
 
         Ok(Self {
             image,
             seeds: loaded_seeds,
+            fingerprint: hash_chain.cache_key(),
         })
     }
 
@@ -540,11 +905,21 @@ impl<'a> LoadedSeeds<'a> {
         self.seeds.iter()
     }
 
+    /// Combined fingerprint over the image, SSL config and all seeds in order.
+    ///
+    /// Returns `None` when an uncacheable seed breaks the hash chain.
+    #[must_use]
+    pub fn fingerprint(&self) -> Option<String> {
+        self.fingerprint.map(hex::encode)
+    }
+
     pub fn print(&self, verbose: bool) {
         #[derive(serde::Serialize)]
         struct Output {
             version: String,
             image: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            fingerprint: Option<String>,
             seeds: Vec<SeedOutput>,
         }
 
@@ -563,6 +938,7 @@ impl<'a> LoadedSeeds<'a> {
         let output = Output {
             version: crate::VERSION_STR.to_string(),
             image: self.image.to_string(),
+            fingerprint: self.fingerprint(),
             seeds: self
                 .seeds
                 .iter()
@@ -585,15 +961,190 @@ impl<'a> LoadedSeeds<'a> {
     }
 }
 
+/// Seeds in application order, every seed follows its dependencies and otherwise keeps
+/// its insertion order
+fn ordered_seeds(
+    definition: &crate::definition::Definition,
+) -> Result<Vec<(&SeedName, &Seed)>, LoadError> {
+    let dependencies = |name: &SeedName| {
+        definition
+            .seed_dependencies
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    };
+
+    for name in definition.seeds.keys() {
+        if let Some(dependency) = dependencies(name)
+            .iter()
+            .find(|dependency| !definition.seeds.contains_key(*dependency))
+        {
+            return Err(LoadError::UnknownDependency {
+                name: name.clone(),
+                dependency: dependency.clone(),
+            });
+        }
+    }
+
+    let mut remaining: Vec<(&SeedName, &Seed)> = definition.seeds.iter().collect();
+    let mut ordered = Vec::with_capacity(remaining.len());
+    let mut applied = std::collections::HashSet::new();
+
+    while !remaining.is_empty() {
+        let ready = remaining.iter().position(|(name, _)| {
+            dependencies(name)
+                .iter()
+                .all(|dependency| applied.contains(dependency))
+        });
+
+        let Some(index) = ready else {
+            // Every remaining seed waits on another remaining seed, following the first
+            // pending dependency from any of them has to run into a cycle.
+            let mut path = vec![remaining[0].0];
+
+            loop {
+                let next = dependencies(path[path.len() - 1])
+                    .iter()
+                    .find(|dependency| !applied.contains(*dependency))
+                    .unwrap();
+
+                if let Some(start) = path.iter().position(|name| *name == next) {
+                    let mut cycle: Vec<SeedName> = path[start..].iter().copied().cloned().collect();
+                    cycle.push(next.clone());
+                    return Err(LoadError::DependencyCycle { cycle });
+                }
+
+                path.push(next);
+            }
+        };
+
+        let (name, seed) = remaining.remove(index);
+        applied.insert(name);
+        ordered.push((name, seed));
+    }
+
+    Ok(ordered)
+}
+
+type SeedStartHook<'a> = Box<dyn Fn(&SeedName) + Send + Sync + 'a>;
+type SeedEndHook<'a> = Box<dyn Fn(&SeedName, std::time::Duration) + Send + Sync + 'a>;
+
+/// Callbacks invoked around the application of each seed.
+///
+/// Used with [`crate::Definition::with_container_hooks`] and
+/// [`crate::Container::reset_hooks`] for instrumentation such as timing or reporting. Seeds
+/// applied in parallel report their own start and elapsed time as a connection picks them up.
+/// No hooks run when the container starts from a snapshot, as no seeds are applied.
+#[derive(Default)]
+pub struct SeedHooks<'a> {
+    on_seed_start: Option<SeedStartHook<'a>>,
+    on_seed_end: Option<SeedEndHook<'a>>,
+}
+
+impl<'a> SeedHooks<'a> {
+    /// Call `hook` with the seed name before the seed is applied.
+    #[must_use]
+    pub fn on_seed_start(self, hook: impl Fn(&SeedName) + Send + Sync + 'a) -> Self {
+        Self {
+            on_seed_start: Some(Box::new(hook)),
+            ..self
+        }
+    }
+
+    /// Call `hook` with the seed name and the time spent applying it after the seed is applied.
+    #[must_use]
+    pub fn on_seed_end(
+        self,
+        hook: impl Fn(&SeedName, std::time::Duration) + Send + Sync + 'a,
+    ) -> Self {
+        Self {
+            on_seed_end: Some(Box::new(hook)),
+            ..self
+        }
+    }
+
+    /// Run `action` for the seed `name`, reporting it as started before and ended after.
+    pub(crate) async fn around<T>(
+        &self,
+        name: &SeedName,
+        action: impl std::future::Future<Output = T>,
+    ) -> T {
+        self.start(name);
+
+        let start = std::time::Instant::now();
+        let value = action.await;
+
+        self.end(name, start.elapsed());
+
+        value
+    }
+
+    pub(crate) fn start(&self, name: &SeedName) {
+        if let Some(hook) = &self.on_seed_start {
+            hook(name)
+        }
+    }
+
+    pub(crate) fn end(&self, name: &SeedName, elapsed: std::time::Duration) {
+        if let Some(hook) = &self.on_seed_end {
+            hook(name, elapsed)
+        }
+    }
+}
+
+impl std::fmt::Debug for SeedHooks<'_> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("SeedHooks")
+            .field("on_seed_start", &self.on_seed_start.is_some())
+            .field("on_seed_end", &self.on_seed_end.is_some())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     #[test]
     fn test_seed_name_rejects_empty_string() {
-        assert_eq!("".parse::<SeedName>(), Err(SeedNameError));
-        assert_eq!(SeedName::try_from(""), Err(SeedNameError));
-        assert_eq!(SeedName::try_from(String::new()), Err(SeedNameError));
+        assert_eq!("".parse::<SeedName>(), Err(SeedNameError::Empty));
+        assert_eq!(SeedName::try_from(""), Err(SeedNameError::Empty));
+        assert_eq!(SeedName::try_from(String::new()), Err(SeedNameError::Empty));
+    }
+
+    #[test]
+    fn test_seed_name_accepts_lowercase_digits_and_hyphens() {
+        assert_eq!(
+            "create-users-table".parse::<SeedName>(),
+            Ok(SeedName("create-users-table".to_string()))
+        );
+        assert_eq!(
+            "seed1".parse::<SeedName>(),
+            Ok(SeedName("seed1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_seed_name_rejects_invalid_characters() {
+        assert_eq!(
+            "Bad Name".parse::<SeedName>(),
+            Err(SeedNameError::InvalidCharacter {
+                name: "Bad Name".to_string(),
+                character: 'B',
+            })
+        );
+        assert_eq!(
+            "a/b".parse::<SeedName>(),
+            Err(SeedNameError::InvalidCharacter {
+                name: "a/b".to_string(),
+                character: '/',
+            })
+        );
+        assert_eq!(
+            "a/b".parse::<SeedName>().unwrap_err().to_string(),
+            r#"Seed name "a/b" contains '/', only lowercase letters, digits and hyphens are allowed"#
+        );
     }
 
     #[test]
@@ -619,6 +1170,313 @@ mod test {
         assert_eq!(name.as_str(), "test-seed");
     }
 
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("*.sql", "001.sql"));
+        assert!(matches_pattern("*.sql", ".sql"));
+        assert!(matches_pattern("00?_*.sql", "001_schema.sql"));
+        assert!(!matches_pattern("*.sql", "001.sql.bak"));
+        assert!(!matches_pattern("*.sql", "README.md"));
+        assert!(!matches_pattern("00?.sql", "0001.sql"));
+    }
+
+    #[test]
+    fn test_load_sql_directory() {
+        let path = std::env::temp_dir().join(format!(
+            "pg-ephemeral-seed-sql-directory-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(path.join("nested.sql")).unwrap();
+        std::fs::write(path.join("002.sql"), "INSERT INTO users VALUES (1);").unwrap();
+        std::fs::write(path.join("001.sql"), "CREATE TABLE users (id INT);").unwrap();
+        std::fs::write(path.join("README.md"), "not sql").unwrap();
+
+        let image = crate::Image::default();
+        let seeds = indexmap::IndexMap::from([(
+            "schema".parse().unwrap(),
+            Seed::SqlDirectory {
+                path: path.clone(),
+                pattern: None,
+                database: None,
+            },
+        )]);
+        let backend = ociman::Backend::Podman {
+            version: semver::Version::new(4, 0, 0),
+        };
+
+        let definition = crate::definition::Definition {
+            seeds,
+            ..crate::definition::Definition::new(backend, image)
+        };
+
+        let loaded_seeds = LoadedSeeds::load(&definition, "main").unwrap();
+        let loaded_seed = loaded_seeds.iter_seeds().next().unwrap();
+
+        std::fs::remove_dir_all(&path).unwrap();
+
+        let LoadedSeed::SqlDirectory { name, files, .. } = loaded_seed else {
+            panic!("expected sql-directory seed, got: {loaded_seed:#?}");
+        };
+
+        assert_eq!(name.as_str(), "schema");
+        assert_eq!(
+            files.as_slice(),
+            [
+                SqlDirectoryFile {
+                    path: path.join("001.sql"),
+                    content: "CREATE TABLE users (id INT);".to_string(),
+                },
+                SqlDirectoryFile {
+                    path: path.join("002.sql"),
+                    content: "INSERT INTO users VALUES (1);".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let path = std::env::temp_dir().join(format!(
+            "pg-ephemeral-seed-fingerprint-{}",
+            std::process::id()
+        ));
+        let first = path.join("first");
+        let second = path.join("second");
+
+        for directory in [&first, &second] {
+            std::fs::create_dir_all(directory).unwrap();
+            std::fs::write(directory.join("schema.sql"), "CREATE TABLE users (id INT);").unwrap();
+            std::fs::write(directory.join("data.sql"), "INSERT INTO users VALUES (1);").unwrap();
+        }
+
+        let image = crate::Image::default();
+        let backend = ociman::Backend::Podman {
+            version: semver::Version::new(4, 0, 0),
+        };
+
+        let fingerprint = |directory: &std::path::Path| {
+            let seeds = indexmap::IndexMap::from([
+                (
+                    "schema".parse().unwrap(),
+                    Seed::SqlFile {
+                        path: directory.join("schema.sql"),
+                        database: None,
+                    },
+                ),
+                (
+                    "data".parse().unwrap(),
+                    Seed::SqlFile {
+                        path: directory.join("data.sql"),
+                        database: None,
+                    },
+                ),
+            ]);
+
+            let definition = crate::definition::Definition {
+                seeds,
+                ..crate::definition::Definition::new(backend.clone(), image.clone())
+            };
+
+            LoadedSeeds::load(&definition, "main")
+                .unwrap()
+                .fingerprint()
+                .unwrap()
+        };
+
+        let before = fingerprint(&first);
+
+        assert_eq!(before, fingerprint(&second));
+
+        std::fs::write(second.join("data.sql"), "INSERT INTO users VALUES (2);").unwrap();
+
+        let after = fingerprint(&second);
+
+        std::fs::remove_dir_all(&path).unwrap();
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn test_fingerprint_connection_and_ssl_contents() {
+        let path = std::env::temp_dir().join(format!(
+            "pg-ephemeral-seed-fingerprint-ssl-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&path).unwrap();
+
+        for name in ["ca.pem", "server.pem", "server.key"] {
+            std::fs::write(path.join(name), name).unwrap();
+        }
+
+        let backend = ociman::Backend::Podman {
+            version: semver::Version::new(4, 0, 0),
+        };
+
+        let fingerprint = |definition: &crate::definition::Definition| {
+            LoadedSeeds::load(definition, "main")
+                .unwrap()
+                .fingerprint()
+                .unwrap()
+        };
+
+        let definition = crate::definition::Definition {
+            ssl_config: Some(crate::definition::SslConfig::UserProvided {
+                hostname: "localhost".parse().unwrap(),
+                ca_cert: path.join("ca.pem"),
+                server_cert: path.join("server.pem"),
+                server_key: path.join("server.key"),
+            }),
+            ..crate::definition::Definition::new(backend, crate::Image::default())
+        };
+
+        let before = fingerprint(&definition);
+
+        assert_ne!(
+            before,
+            fingerprint(&crate::definition::Definition {
+                superuser: "admin".parse().unwrap(),
+                ..definition.clone()
+            })
+        );
+        assert_ne!(
+            before,
+            fingerprint(&crate::definition::Definition {
+                database: "app".parse().unwrap(),
+                ..definition.clone()
+            })
+        );
+
+        std::fs::write(path.join("ca.pem"), "rotated").unwrap();
+
+        let after = fingerprint(&definition);
+
+        std::fs::remove_dir_all(&path).unwrap();
+
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn test_fingerprint_image_registry_and_repository() {
+        let backend = ociman::Backend::Podman {
+            version: semver::Version::new(4, 0, 0),
+        };
+
+        let image: crate::Image = "17.1".parse().unwrap();
+
+        let fingerprint = |image: &crate::Image| {
+            let definition = crate::definition::Definition::new(backend.clone(), image.clone());
+
+            LoadedSeeds::load(&definition, "main")
+                .unwrap()
+                .fingerprint()
+                .unwrap()
+        };
+
+        let official = fingerprint(&image);
+
+        assert_ne!(
+            official,
+            fingerprint(&image.clone().registry("internal.registry".parse().unwrap()))
+        );
+        assert_ne!(
+            official,
+            fingerprint(&image.repository("mirror/postgis".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_uncacheable() {
+        let image = crate::Image::default();
+        let backend = ociman::Backend::Podman {
+            version: semver::Version::new(4, 0, 0),
+        };
+        let seeds = indexmap::IndexMap::from([(
+            "migrate".parse().unwrap(),
+            Seed::Command {
+                command: Command::new("migrate", ["up"]),
+                cache: CommandCacheConfig::None,
+                database: None,
+            },
+        )]);
+
+        let definition = crate::definition::Definition {
+            seeds,
+            ..crate::definition::Definition::new(backend, image)
+        };
+
+        let loaded_seeds = LoadedSeeds::load(&definition, "main").unwrap();
+
+        assert_eq!(loaded_seeds.fingerprint(), None);
+    }
+
+    fn script_seeds_definition(names: &[&str]) -> crate::definition::Definition {
+        let backend = ociman::Backend::Podman {
+            version: semver::Version::new(4, 0, 0),
+        };
+
+        let seeds = names
+            .iter()
+            .map(|name| {
+                (
+                    name.parse().unwrap(),
+                    Seed::Script {
+                        script: format!("echo {name}"),
+                        database: None,
+                    },
+                )
+            })
+            .collect();
+
+        crate::definition::Definition {
+            seeds,
+            ..crate::definition::Definition::new(backend, crate::Image::default())
+        }
+    }
+
+    #[test]
+    fn test_load_orders_seeds_after_dependencies() {
+        let definition = script_seeds_definition(&["data", "extra", "schema", "roles"])
+            .seed_after("data".parse().unwrap(), ["schema".parse().unwrap()])
+            .seed_after("schema".parse().unwrap(), ["roles".parse().unwrap()]);
+
+        let loaded_seeds = LoadedSeeds::load(&definition, "main").unwrap();
+
+        assert_eq!(
+            loaded_seeds
+                .iter_seeds()
+                .map(|seed| seed.name().as_str())
+                .collect::<Vec<_>>(),
+            ["extra", "roles", "schema", "data"]
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_dependency_cycle() {
+        let definition = script_seeds_definition(&["extra", "data", "schema"])
+            .seed_after("data".parse().unwrap(), ["schema".parse().unwrap()])
+            .seed_after("schema".parse().unwrap(), ["data".parse().unwrap()]);
+
+        let error = LoadedSeeds::load(&definition, "main").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to load seeds: dependency cycle data -> schema -> data"
+        );
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_dependency() {
+        let definition = script_seeds_definition(&["data"])
+            .seed_after("data".parse().unwrap(), ["schema".parse().unwrap()]);
+
+        let error = LoadedSeeds::load(&definition, "main").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to load seed data: depends on unknown seed schema"
+        );
+    }
+
     #[test]
     fn test_cache_status_uncacheable() {
         let loaded_seed = LoadedSeed::Command {
@@ -626,6 +1484,7 @@ mod test {
             cache_key_output: None,
             name: "run-migrations".parse().unwrap(),
             command: Command::new("migrate", ["up"]),
+            database: None,
         };
 
         assert!(loaded_seed.cache_status().reference().is_none());
@@ -646,6 +1505,7 @@ mod test {
             name: "schema".parse().unwrap(),
             path: "schema.sql".into(),
             content: "CREATE TABLE test();".to_string(),
+            database: None,
         };
 
         assert_eq!(loaded_seed.cache_status().reference(), Some(&reference));
@@ -666,9 +1526,36 @@ mod test {
             name: "schema".parse().unwrap(),
             path: "schema.sql".into(),
             content: "CREATE TABLE test();".to_string(),
+            database: None,
         };
 
         assert_eq!(loaded_seed.cache_status().reference(), Some(&reference));
         assert!(loaded_seed.cache_status().is_hit());
     }
+
+    #[test]
+    fn test_apply_error_from_command_error() {
+        let name: SeedName = "broken".parse().unwrap();
+
+        let error = cmd_proc::Command::new("sh")
+            .arguments(["-c", "exit 3"])
+            .status()
+            .unwrap_err();
+
+        let error = ApplyError::from_command_error(&name, error);
+
+        assert_eq!(
+            error.to_string(),
+            "Failed to apply seed broken: exited with exit status: 3"
+        );
+
+        let error = cmd_proc::Command::new("pg-ephemeral-does-not-exist")
+            .status()
+            .unwrap_err();
+
+        assert!(matches!(
+            ApplyError::from_command_error(&name, error),
+            ApplyError::Execute { source, .. } if source.kind() == std::io::ErrorKind::NotFound
+        ));
+    }
 }
@@ -19,6 +19,160 @@ async fn test_base_feature() {
         .await
 }
 
+#[tokio::test]
+async fn test_with_pool() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .with_container(async |container| {
+            container
+                .with_pool(4, async |pool| {
+                    let mut queries = tokio::task::JoinSet::new();
+
+                    for value in 0..16 {
+                        let pool = pool.clone();
+
+                        queries.spawn(async move {
+                            let row = sqlx::query("SELECT $1::int4, pg_backend_pid()")
+                                .bind(value)
+                                .fetch_one(&pool)
+                                .await
+                                .unwrap();
+                            assert_eq!(sqlx::Row::get::<i32, usize>(&row, 0), value);
+                            sqlx::Row::get::<i32, usize>(&row, 1)
+                        });
+                    }
+
+                    let backend_pids: std::collections::BTreeSet<i32> =
+                        queries.join_all().await.into_iter().collect();
+
+                    assert!((1..=4).contains(&backend_pids.len()));
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_persist() {
+    let backend = ociman::test_backend_setup!();
+
+    let definition = common::test_definition(backend.clone())
+        .instance_name("persist-test".parse().unwrap())
+        .persist(true);
+    let name = definition.persisted_container_name().unwrap();
+
+    definition
+        .with_container(async |container| {
+            container
+                .apply_sql("CREATE TABLE persisted (id INTEGER)")
+                .await
+        })
+        .await;
+
+    let running = backend
+        .command()
+        .arguments([
+            "container",
+            "inspect",
+            "--format",
+            "{{.State.Running}}",
+            &name,
+        ])
+        .stdout()
+        .string()
+        .unwrap();
+
+    let reattached = definition
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    sqlx::query_scalar::<_, bool>("SELECT to_regclass('persisted') IS NOT NULL")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap()
+                })
+                .await
+        })
+        .await;
+
+    backend
+        .command()
+        .arguments(["container", "rm", "--force", &name])
+        .stdout()
+        .bytes()
+        .unwrap();
+
+    assert_eq!(running.trim(), "true");
+    assert!(reattached);
+}
+
+#[tokio::test]
+async fn test_persist_stop_start() {
+    let backend = ociman::test_backend_setup!();
+
+    let definition = common::test_definition(backend.clone())
+        .instance_name("persist-stop-start".parse().unwrap())
+        .persist(true);
+    let name = definition.persisted_container_name().unwrap();
+
+    definition.start_detached().await.stop();
+
+    let restarted = definition.start_detached().await;
+
+    restarted.container().apply_sql("SELECT 1").await;
+
+    let running = backend
+        .container(&name)
+        .is_some_and(|container| container.is_running());
+
+    restarted.stop();
+
+    backend
+        .command()
+        .arguments(["container", "rm", "--force", &name])
+        .stdout()
+        .bytes()
+        .unwrap();
+
+    assert!(running);
+}
+
+#[tokio::test]
+async fn test_attach_external() {
+    let backend = ociman::test_backend_setup!();
+
+    let dir = common::TestDir::new("attach-external");
+    dir.write_file("schema.sql", "CREATE TABLE external_seeded (id INT)");
+
+    let analytics: pg_client::Database = "analytics".parse().unwrap();
+
+    // The server already has the additional database, seeding must not create it again
+    common::test_definition(backend.clone())
+        .additional_database(analytics.clone())
+        .with_container(async |container| {
+            let definition = common::test_definition(backend.clone())
+                .additional_database(analytics.clone())
+                .apply_file("schema".parse().unwrap(), dir.path.join("schema.sql"))
+                .unwrap();
+
+            let external = definition.attach_external(container.client_config().clone());
+
+            external.apply_seeds().await;
+
+            container
+                .with_connection(async |connection| {
+                    let row = sqlx::query("SELECT to_regclass('external_seeded') IS NOT NULL")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+                    assert!(sqlx::Row::get::<bool, usize>(&row, 0))
+                })
+                .await
+        })
+        .await
+}
+
 #[tokio::test]
 async fn test_ssl_generated() {
     let backend = ociman::test_backend_setup!();
@@ -41,37 +195,365 @@ async fn test_ssl_generated() {
         .await
 }
 
+#[tokio::test]
+async fn test_ssl_user_provided() {
+    let backend = ociman::test_backend_setup!();
+
+    let hostname = "postgresql.example.com";
+    let bundle = pg_ephemeral::certificate::Bundle::generate(hostname).unwrap();
+
+    let dir = common::TestDir::new("ssl-user-provided");
+    dir.write_file("root.crt", &bundle.ca_cert_pem);
+    dir.write_file("server.crt", &bundle.server_cert_pem);
+    dir.write_file("server.key", &bundle.server_key_pem);
+
+    common::test_definition(backend)
+        .ssl_config(pg_ephemeral::definition::SslConfig::UserProvided {
+            hostname: hostname.parse().unwrap(),
+            ca_cert: dir.path.join("root.crt"),
+            server_cert: dir.path.join("server.crt"),
+            server_key: dir.path.join("server.key"),
+        })
+        .with_container(async |container| {
+            let config = container.client_config();
+
+            assert_eq!(config.ssl_mode, pg_client::SslMode::VerifyFull);
+            assert_eq!(
+                config.ssl_root_cert,
+                Some(pg_client::SslRootCert::File(dir.path.join("root.crt")))
+            );
+
+            container
+                .with_connection(async |connection| {
+                    let row = sqlx::query("SELECT true")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+                    assert!(sqlx::Row::get::<bool, usize>(&row, 0))
+                })
+                .await
+        })
+        .await
+}
+
+#[test]
+fn test_wait_available_failure_includes_logs() {
+    let backend = ociman::test_backend_setup!();
+
+    let hostname = "postgresql.example.com";
+    let bundle = pg_ephemeral::certificate::Bundle::generate(hostname).unwrap();
+    let other_bundle = pg_ephemeral::certificate::Bundle::generate(hostname).unwrap();
+
+    // Server key does not belong to the server certificate, postgres refuses to start
+    let dir = common::TestDir::new("ssl-mismatched-key");
+    dir.write_file("root.crt", &bundle.ca_cert_pem);
+    dir.write_file("server.crt", &bundle.server_cert_pem);
+    dir.write_file("server.key", &other_bundle.server_key_pem);
+
+    let definition = common::test_definition(backend)
+        .wait_available_timeout(std::time::Duration::from_secs(10))
+        .ssl_config(pg_ephemeral::definition::SslConfig::UserProvided {
+            hostname: hostname.parse().unwrap(),
+            ca_cert: dir.path.join("root.crt"),
+            server_cert: dir.path.join("server.crt"),
+            server_key: dir.path.join("server.key"),
+        });
+
+    let payload = std::thread::spawn(move || {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(definition.with_container(async |_container| {}))
+    })
+    .join()
+    .unwrap_err();
+
+    let message = payload.downcast_ref::<String>().unwrap();
+
+    assert!(
+        message.contains("container log lines:"),
+        "missing logs in: {message}"
+    );
+    assert!(message.contains("FATAL"), "missing logs in: {message}");
+}
+
+#[tokio::test]
+async fn test_custom_superuser_and_database() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .superuser("admin".parse().unwrap())
+        .database("app".parse().unwrap())
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let row = sqlx::query("SELECT current_user::text, current_database()::text")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+                    assert_eq!(sqlx::Row::get::<String, usize>(&row, 0), "admin");
+                    assert_eq!(sqlx::Row::get::<String, usize>(&row, 1), "app");
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_host_port() {
+    let backend = ociman::test_backend_setup!();
+
+    let host_port = {
+        let listener = std::net::TcpListener::bind((std::net::Ipv4Addr::LOCALHOST, 0)).unwrap();
+        listener.local_addr().unwrap().port()
+    };
+
+    common::test_definition(backend)
+        .host_port(host_port.into())
+        .with_container(async |container| {
+            let pg_client::Endpoint::Network { port, .. } = &container.client_config().endpoint
+            else {
+                panic!("expected network endpoint");
+            };
+
+            assert_eq!(*port, Some(host_port.into()));
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_extensions() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .extension("uuid-ossp".parse().unwrap())
+        .extension("pgcrypto".parse().unwrap())
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let rows = sqlx::query(
+                        "SELECT extname::text FROM pg_extension WHERE extname <> 'plpgsql' ORDER BY extname",
+                    )
+                    .fetch_all(connection)
+                    .await
+                    .unwrap();
+
+                    let actual: Vec<String> = rows
+                        .iter()
+                        .map(|row| sqlx::Row::get::<String, usize>(row, 0))
+                        .collect();
+
+                    assert_eq!(actual, ["pgcrypto", "uuid-ossp"]);
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_additional_databases() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .additional_database("analytics".parse().unwrap())
+        .additional_database("jobs".parse().unwrap())
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let rows = sqlx::query(
+                        "SELECT datname::text FROM pg_database WHERE datname IN ('analytics', 'jobs') ORDER BY datname",
+                    )
+                    .fetch_all(connection)
+                    .await
+                    .unwrap();
+
+                    let actual: Vec<String> = rows
+                        .iter()
+                        .map(|row| sqlx::Row::get::<String, usize>(row, 0))
+                        .collect();
+
+                    assert_eq!(actual, ["analytics", "jobs"]);
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_write_env_file() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("write-env-file");
+
+    common::test_definition(backend)
+        .with_container(async |container| {
+            let env_path = dir.path.join(".env");
+            let envrc_path = dir.path.join(".envrc");
+
+            container.write_env_file(&env_path).unwrap();
+            container.write_envrc(&envrc_path).unwrap();
+
+            let env_file = std::fs::read_to_string(env_path).unwrap();
+
+            for name in container.pg_env().keys() {
+                assert!(
+                    env_file
+                        .lines()
+                        .any(|line| line.starts_with(&format!("{name}="))),
+                    "missing {name} in {env_file}"
+                );
+            }
+
+            assert!(
+                env_file
+                    .lines()
+                    .any(|line| line == format!("DATABASE_URL='{}'", container.database_url())),
+                "missing DATABASE_URL in {env_file}"
+            );
+
+            let envrc = std::fs::read_to_string(envrc_path).unwrap();
+
+            let expected: String = env_file
+                .lines()
+                .map(|line| format!("export {line}\n"))
+                .collect();
+
+            assert_eq!(envrc, expected);
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_server_parameters() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .server_parameter("max_connections".parse().unwrap(), "42")
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let row = sqlx::query("SHOW max_connections")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+                    assert_eq!(sqlx::Row::get::<String, usize>(&row, 0), "42");
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_start_detached() {
+    let backend = ociman::test_backend_setup!();
+
+    let running = common::test_definition(backend).start_detached().await;
+
+    running
+        .container()
+        .with_connection(async |connection| {
+            let row = sqlx::query("SELECT true")
+                .fetch_one(connection)
+                .await
+                .unwrap();
+            assert!(sqlx::Row::get::<bool, usize>(&row, 0))
+        })
+        .await;
+
+    let client_config = running.client_config().clone();
+
+    running.stop();
+
+    let sqlx_config = client_config.to_sqlx_connect_options().unwrap();
+
+    assert!(sqlx::ConnectOptions::connect(&sqlx_config).await.is_err());
+}
+
+#[tokio::test]
+async fn test_readiness_probe_query() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .readiness_probe(pg_ephemeral::ReadinessProbe::Query("SELECT 1".to_string()))
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let row = sqlx::query("SELECT true")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+                    assert!(sqlx::Row::get::<bool, usize>(&row, 0))
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_readiness_probe_waits_for_table() {
+    let backend = ociman::test_backend_setup!();
+
+    let probe = pg_ephemeral::ReadinessProbe::Query(
+        "SELECT 1 FROM pg_tables WHERE tablename = 'migrations'".to_string(),
+    );
+
+    common::test_definition(backend)
+        .with_container(async |container| {
+            let create_table = async {
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                container
+                    .apply_sql("CREATE TABLE migrations (version INTEGER)")
+                    .await
+            };
+
+            let start = std::time::Instant::now();
+
+            let (result, ()) = tokio::join!(container.try_wait_for(&probe), create_table);
+
+            result.unwrap();
+
+            assert!(start.elapsed() >= std::time::Duration::from_millis(500));
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_tmpfs_storage() {
+    let backend = ociman::test_backend_setup!();
+
+    common::test_definition(backend)
+        .storage(pg_ephemeral::Storage::Tmpfs)
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let row = sqlx::query("SELECT true")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+                    assert!(sqlx::Row::get::<bool, usize>(&row, 0))
+                })
+                .await
+        })
+        .await
+}
+
 #[test]
 fn test_config_file() {
     assert_eq!(
         pg_ephemeral::InstanceMap::from([
             (
                 pg_ephemeral::InstanceName("a".to_string()),
-                pg_ephemeral::Instance {
-                    application_name: None,
-                    backend: ociman::backend::Selection::Docker,
-                    database: pg_client::Database::POSTGRES,
-                    seeds: indexmap::IndexMap::new(),
-                    ssl_config: None,
-                    superuser: pg_client::User::POSTGRES,
-                    image: "17.1".parse().unwrap(),
-                    cross_container_access: false,
-                    wait_available_timeout: std::time::Duration::from_secs(10),
-                }
+                pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "17.1".parse().unwrap()
+                )
             ),
             (
                 pg_ephemeral::InstanceName("b".to_string()),
-                pg_ephemeral::Instance {
-                    application_name: None,
-                    backend: ociman::backend::Selection::Podman,
-                    database: pg_client::Database::POSTGRES,
-                    seeds: indexmap::IndexMap::new(),
-                    ssl_config: None,
-                    superuser: pg_client::User::POSTGRES,
-                    image: "17.2".parse().unwrap(),
-                    cross_container_access: false,
-                    wait_available_timeout: std::time::Duration::from_secs(10),
-                }
+                pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Podman,
+                    "17.2".parse().unwrap()
+                )
             )
         ]),
         pg_ephemeral::Config::load_toml_file(
@@ -85,31 +567,17 @@ fn test_config_file() {
         pg_ephemeral::InstanceMap::from([
             (
                 pg_ephemeral::InstanceName("a".to_string()),
-                pg_ephemeral::Instance {
-                    application_name: None,
-                    backend: ociman::backend::Selection::Docker,
-                    database: pg_client::Database::POSTGRES,
-                    seeds: indexmap::IndexMap::new(),
-                    ssl_config: None,
-                    superuser: pg_client::User::POSTGRES,
-                    image: "18.0".parse().unwrap(),
-                    cross_container_access: false,
-                    wait_available_timeout: std::time::Duration::from_secs(10),
-                }
+                pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "18.0".parse().unwrap()
+                )
             ),
             (
                 pg_ephemeral::InstanceName("b".to_string()),
-                pg_ephemeral::Instance {
-                    application_name: None,
-                    backend: ociman::backend::Selection::Docker,
-                    database: pg_client::Database::POSTGRES,
-                    seeds: indexmap::IndexMap::new(),
-                    ssl_config: None,
-                    superuser: pg_client::User::POSTGRES,
-                    image: "18.0".parse().unwrap(),
-                    cross_container_access: false,
-                    wait_available_timeout: std::time::Duration::from_secs(10),
-                }
+                pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "18.0".parse().unwrap()
+                )
             )
         ]),
         pg_ephemeral::Config::load_toml_file(
@@ -117,9 +585,7 @@ fn test_config_file() {
             &pg_ephemeral::config::InstanceDefinition {
                 backend: Some(ociman::backend::Selection::Docker),
                 image: Some("18.0".parse().unwrap()),
-                seeds: indexmap::IndexMap::new(),
-                ssl_config: None,
-                wait_available_timeout: None,
+                ..pg_ephemeral::config::InstanceDefinition::empty()
             }
         )
         .unwrap()
@@ -127,94 +593,891 @@ fn test_config_file() {
 }
 
 #[test]
-fn test_config_file_no_explicit_instance() {
-    assert_eq!(
-        pg_ephemeral::InstanceMap::from([(
-            pg_ephemeral::InstanceName("main".to_string()),
-            pg_ephemeral::Instance {
-                application_name: None,
-                backend: ociman::backend::Selection::Docker,
-                database: pg_client::Database::POSTGRES,
-                seeds: indexmap::IndexMap::new(),
-                ssl_config: None,
-                superuser: pg_client::User::POSTGRES,
-                image: "17.1".parse().unwrap(),
-                cross_container_access: false,
-                wait_available_timeout: std::time::Duration::from_secs(10),
-            }
-        ),]),
-        pg_ephemeral::Config::load_toml_file(
-            "tests/database_no_explicit_instance.toml",
-            &pg_ephemeral::config::InstanceDefinition::empty()
-        )
+fn test_config_file_no_explicit_instance() {
+    assert_eq!(
+        pg_ephemeral::InstanceMap::from([(
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::Instance::new(
+                ociman::backend::Selection::Docker,
+                "17.1".parse().unwrap()
+            )
+        ),]),
+        pg_ephemeral::Config::load_toml_file(
+            "tests/database_no_explicit_instance.toml",
+            &pg_ephemeral::config::InstanceDefinition::empty()
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        pg_ephemeral::InstanceMap::from([(
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::Instance::new(
+                ociman::backend::Selection::Podman,
+                "18.0".parse().unwrap()
+            )
+        ),]),
+        pg_ephemeral::Config::load_toml_file(
+            "tests/database_no_explicit_instance.toml",
+            &pg_ephemeral::config::InstanceDefinition {
+                backend: Some(ociman::backend::Selection::Podman),
+                image: Some("18.0".parse().unwrap()),
+                ..pg_ephemeral::config::InstanceDefinition::empty()
+            }
+        )
+        .unwrap()
+    )
+}
+
+#[test]
+fn test_config_ssl() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [ssl_config]
+        hostname = "postgresql.example.com"
+
+        [instances.main]
+    "#};
+
+    assert_eq!(
+        pg_ephemeral::InstanceMap::from([(
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::Instance {
+                ssl_config: Some(pg_ephemeral::definition::SslConfig::Generated {
+                    hostname: "postgresql.example.com".parse().unwrap(),
+                }),
+                ..pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "18.0".parse().unwrap()
+                )
+            }
+        )]),
+        pg_ephemeral::Config::load_toml(config_str)
+            .unwrap()
+            .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+            .unwrap()
+    )
+}
+
+#[test]
+fn test_config_ssl_user_provided() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [ssl_config]
+        hostname = "postgresql.example.com"
+        ca_cert = "certs/root.crt"
+        server_cert = "certs/server.crt"
+        server_key = "certs/server.key"
+
+        [instances.main]
+    "#};
+
+    assert_eq!(
+        pg_ephemeral::InstanceMap::from([(
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::Instance {
+                ssl_config: Some(pg_ephemeral::definition::SslConfig::UserProvided {
+                    hostname: "postgresql.example.com".parse().unwrap(),
+                    ca_cert: "certs/root.crt".into(),
+                    server_cert: "certs/server.crt".into(),
+                    server_key: "certs/server.key".into(),
+                }),
+                ..pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "18.0".parse().unwrap()
+                )
+            }
+        )]),
+        pg_ephemeral::Config::load_toml(config_str)
+            .unwrap()
+            .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+            .unwrap()
+    )
+}
+
+#[test]
+fn test_config_ssl_user_provided_incomplete() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [ssl_config]
+        hostname = "postgresql.example.com"
+        ca_cert = "certs/root.crt"
+
+        [instances.main]
+    "#};
+
+    assert!(pg_ephemeral::Config::load_toml(config_str).is_err());
+}
+
+#[test]
+fn test_config_superuser_and_database() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        superuser = "admin"
+        database = "app"
+    "#};
+
+    assert_eq!(
+        pg_ephemeral::InstanceMap::from([(
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::Instance {
+                database: "app".parse().unwrap(),
+                superuser: "admin".parse().unwrap(),
+                ..pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "18.0".parse().unwrap()
+                )
+            }
+        )]),
+        pg_ephemeral::Config::load_toml(config_str)
+            .unwrap()
+            .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+            .unwrap()
+    )
+}
+
+#[test]
+fn test_config_application_name_and_cross_container_access() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        application_name = "myapp"
+        cross_container_access = true
+    "#};
+
+    assert_eq!(
+        pg_ephemeral::InstanceMap::from([(
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::Instance {
+                application_name: Some("myapp".parse().unwrap()),
+                cross_container_access: true,
+                ..pg_ephemeral::Instance::new(
+                    ociman::backend::Selection::Docker,
+                    "18.0".parse().unwrap()
+                )
+            }
+        )]),
+        pg_ephemeral::Config::load_toml(config_str)
+            .unwrap()
+            .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+            .unwrap()
+    )
+}
+
+#[test]
+fn test_config_host_port() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        host_port = 55432
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = instance_map
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    assert_eq!(instance.host_port, Some(pg_client::Port::new(55432)));
+}
+
+#[test]
+fn test_config_host_port_zero() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        host_port = 0
+    "#};
+
+    assert!(pg_ephemeral::Config::load_toml(config_str).is_err());
+}
+
+#[test]
+fn test_config_resource_limits() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        memory_limit = "512m"
+        cpus = 1.5
+
+        [instances.bytes]
+        memory_limit = 268435456
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let backend = ociman::Backend::Docker {
+        version: semver::Version::new(28, 0, 0),
+    };
+
+    let definition = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .definition_with_backend(backend.clone())
+    };
+
+    let main = definition("main");
+
+    assert_eq!(
+        main.to_ociman_definition(),
+        ociman::Definition::new(backend.clone(), (&main.image).into())
+            .memory("512m")
+            .cpus("1.5")
+    );
+
+    let bytes = definition("bytes");
+
+    assert_eq!(
+        bytes.to_ociman_definition(),
+        ociman::Definition::new(backend.clone(), (&bytes.image).into()).memory("268435456")
+    );
+}
+
+#[test]
+fn test_config_invalid_memory_limit() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+        memory_limit = "lots"
+    "#};
+
+    assert!(pg_ephemeral::Config::load_toml(config_str).is_err());
+}
+
+#[test]
+fn test_config_bind_address() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        bind_address = "172.17.0.1"
+
+        [instances.other]
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let bind_address = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .definition_with_backend(ociman::Backend::Docker {
+                version: semver::Version::new(28, 0, 0),
+            })
+            .bind_address
+    };
+
+    assert_eq!(
+        bind_address("main"),
+        Some(std::net::IpAddr::V4(std::net::Ipv4Addr::new(172, 17, 0, 1)))
+    );
+    assert_eq!(bind_address("other"), None);
+}
+
+#[test]
+fn test_config_parallel_seeds() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        parallel_seeds = 4
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = instance_map
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    assert_eq!(instance.parallel_seeds, std::num::NonZeroU16::new(4));
+}
+
+#[test]
+fn test_config_extensions() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        extensions = ["uuid-ossp", "pgcrypto"]
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = instance_map
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    let expected: Vec<pg_client::identifier::Extension> =
+        vec!["uuid-ossp".parse().unwrap(), "pgcrypto".parse().unwrap()];
+
+    assert_eq!(instance.extensions, expected);
+}
+
+#[test]
+fn test_config_additional_databases() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        additional_databases = ["analytics", "jobs"]
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = instance_map
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    let expected: Vec<pg_client::Database> =
+        vec!["analytics".parse().unwrap(), "jobs".parse().unwrap()];
+
+    assert_eq!(instance.additional_databases, expected);
+}
+
+#[test]
+fn test_config_seed_database() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        additional_databases = ["analytics"]
+
+        [instances.main.seeds.events]
+        type = "sql-file"
+        path = "events.sql"
+        database = "analytics"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = instance_map
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    let seed_name: pg_ephemeral::SeedName = "events".parse().unwrap();
+    let seed = instance.seeds.get(&seed_name).unwrap();
+
+    assert_eq!(
+        *seed,
+        pg_ephemeral::Seed::SqlFile {
+            path: "events.sql".into(),
+            database: Some("analytics".parse().unwrap()),
+        }
+    );
+}
+
+#[test]
+fn test_config_server_parameters() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main.server_parameters]
+        shared_buffers = "256MB"
+        max_connections = "200"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = instance_map
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    assert_eq!(
+        instance.server_parameters,
+        std::collections::BTreeMap::from([
+            ("max_connections".parse().unwrap(), "200".to_string()),
+            ("shared_buffers".parse().unwrap(), "256MB".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_config_server_parameters_invalid_name() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main.server_parameters]
+        "shared_buffers=1 -c fsync" = "off"
+    "#};
+
+    assert!(pg_ephemeral::Config::load_toml(config_str).is_err());
+}
+
+#[test]
+fn test_config_image_registry() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "17.1"
+        image_registry = "internal.registry"
+        image_repository = "mirror/postgres"
+
+        [instances.main]
+
+        [instances.other]
+        image_registry = "localhost:5000"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let reference = |name: &str| {
+        let image = &instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .image;
+
+        ociman::image::Reference::from(image).to_string()
+    };
+
+    assert_eq!(reference("main"), "internal.registry/mirror/postgres:17.1");
+    assert_eq!(reference("other"), "localhost:5000/mirror/postgres:17.1");
+}
+
+#[test]
+fn test_config_network() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+        network = "app-net"
+
+        [instances.main]
+
+        [instances.other]
+        network = "other-net"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let network = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .network
+            .clone()
+    };
+
+    assert_eq!(network("main").as_deref(), Some("app-net"));
+    assert_eq!(network("other").as_deref(), Some("other-net"));
+}
+
+#[test]
+fn test_config_validate_seeds() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main.seeds.env-table]
+        type = "sql-file"
+        path = "tests/fixtures/create_seed_env_table.sql"
+
+        [instances.main.seeds.historic]
+        type = "sql-file"
+        path = "tests/fixtures/does_not_exist.sql"
+        git_revision = "HEAD"
+
+        [instances.other.seeds.missing]
+        type = "sql-file"
+        path = "tests/fixtures/does_not_exist.sql"
+
+        [instances.directory.seeds.fixtures]
+        type = "sql-directory"
+        path = "tests/fixtures"
+
+        [instances.directory.seeds.not-a-file]
+        type = "sql-file"
+        path = "tests/fixtures"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let validate = |name: &str| {
+        let instance_name = pg_ephemeral::InstanceName(name.to_string());
+
+        instance_map
+            .get(&instance_name)
+            .unwrap()
+            .validate_seeds(&instance_name)
+    };
+
+    assert_eq!(validate("main"), Ok(()));
+
+    let error = validate("other").unwrap_err();
+
+    assert_eq!(
+        error,
+        pg_ephemeral::config::Error::SeedPath {
+            instance_name: pg_ephemeral::InstanceName("other".to_string()),
+            seed_name: "missing".parse().unwrap(),
+            path: "tests/fixtures/does_not_exist.sql".into(),
+            error: pg_ephemeral::config::IoError(std::io::ErrorKind::NotFound),
+        }
+    );
+    assert!(error.to_string().contains("seed missing"), "{error}");
+
+    let directory_error = || pg_ephemeral::config::Error::SeedPath {
+        instance_name: pg_ephemeral::InstanceName("directory".to_string()),
+        seed_name: "not-a-file".parse().unwrap(),
+        path: "tests/fixtures".into(),
+        error: pg_ephemeral::config::IoError(std::io::ErrorKind::IsADirectory),
+    };
+
+    assert_eq!(validate("directory"), Err(directory_error()));
+
+    // Instances are validated in name order
+    assert_eq!(
+        pg_ephemeral::config::validate_seeds(&instance_map),
+        Err(directory_error())
+    );
+}
+
+#[test]
+fn test_config_single_definition() {
+    use indoc::indoc;
+
+    let config = pg_ephemeral::Config::load_toml(indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+        database = "app"
+    "#})
+    .unwrap();
+
+    let overwrites = pg_ephemeral::config::InstanceDefinition::empty();
+
+    let definition = config.single_definition(&overwrites).unwrap();
+
+    assert_eq!(definition.database.as_str(), "app");
+    assert_eq!(
+        config
+            .definition_for(&pg_ephemeral::InstanceName::default(), &overwrites)
+            .unwrap(),
+        definition
+    );
+}
+
+#[test]
+fn test_config_single_definition_multiple_instances() {
+    use indoc::indoc;
+
+    let config = pg_ephemeral::Config::load_toml(indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        [instances.other]
+    "#})
+    .unwrap();
+
+    let overwrites = pg_ephemeral::config::InstanceDefinition::empty();
+
+    let error = config.single_definition(&overwrites).unwrap_err();
+
+    assert_eq!(
+        error,
+        pg_ephemeral::config::Error::NotSingleInstance(vec![
+            pg_ephemeral::InstanceName("main".to_string()),
+            pg_ephemeral::InstanceName("other".to_string()),
+        ])
+    );
+    assert_eq!(
+        error.to_string(),
+        "Expected exactly one instance, config defines: [main, other]"
+    );
+
+    assert_eq!(
+        config.definition_for(
+            &pg_ephemeral::InstanceName("missing".to_string()),
+            &overwrites
+        ),
+        Err(pg_ephemeral::config::Error::UnknownInstance(
+            pg_ephemeral::InstanceName("missing".to_string())
+        ))
+    );
+}
+
+#[test]
+fn test_config_initdb_args() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+        encoding = "UTF8"
+
+        [instances.main]
+        locale = "C"
+
+        [instances.checksums]
+        initdb_args = ["--data-checksums"]
+
+        [instances.other]
+        encoding = "LATIN1"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let initdb_args = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .definition_with_backend(ociman::Backend::Docker {
+                version: semver::Version::new(28, 0, 0),
+            })
+            .postgres_initdb_args()
+    };
+
+    assert_eq!(
+        initdb_args("main").as_deref(),
+        Some("--encoding=UTF8 --locale=C")
+    );
+    assert_eq!(
+        initdb_args("checksums").as_deref(),
+        Some("--encoding=UTF8 --data-checksums")
+    );
+    assert_eq!(initdb_args("other").as_deref(), Some("--encoding=LATIN1"));
+
+    let instance_map = pg_ephemeral::Config::load_toml(r#"image = "18.0""#)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    assert_eq!(
+        instance_map
+            .values()
+            .next()
+            .unwrap()
+            .definition_with_backend(ociman::Backend::Docker {
+                version: semver::Version::new(28, 0, 0),
+            })
+            .postgres_initdb_args(),
+        None
+    );
+}
+
+#[test]
+fn test_config_keep_on_failure() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+        keep_on_failure = true
+
+        [instances.main]
+
+        [instances.other]
+        keep_on_failure = false
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let keep_on_failure = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .keep_on_failure
+    };
+
+    assert!(keep_on_failure("main"));
+    assert!(!keep_on_failure("other"));
+}
+
+#[test]
+fn test_config_persist() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main]
+        persist = true
+
+        [instances.other]
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
         .unwrap()
-    );
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let persisted_container_name = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
+            .unwrap()
+            .definition_with_backend(ociman::Backend::Docker {
+                version: semver::Version::new(28, 0, 0),
+            })
+            .persisted_container_name()
+    };
 
     assert_eq!(
-        pg_ephemeral::InstanceMap::from([(
-            pg_ephemeral::InstanceName("main".to_string()),
-            pg_ephemeral::Instance {
-                application_name: None,
-                backend: ociman::backend::Selection::Podman,
-                database: pg_client::Database::POSTGRES,
-                seeds: indexmap::IndexMap::new(),
-                ssl_config: None,
-                superuser: pg_client::User::POSTGRES,
-                image: "18.0".parse().unwrap(),
-                cross_container_access: false,
-                wait_available_timeout: std::time::Duration::from_secs(10),
-            }
-        ),]),
-        pg_ephemeral::Config::load_toml_file(
-            "tests/database_no_explicit_instance.toml",
-            &pg_ephemeral::config::InstanceDefinition {
-                backend: Some(ociman::backend::Selection::Podman),
-                image: Some("18.0".parse().unwrap()),
-                seeds: indexmap::IndexMap::new(),
-                ssl_config: None,
-                wait_available_timeout: None,
-            }
-        )
-        .unwrap()
-    )
+        persisted_container_name("main"),
+        Some("pg-ephemeral-main".to_string())
+    );
+    assert_eq!(persisted_container_name("other"), None);
 }
 
 #[test]
-fn test_config_ssl() {
+fn test_config_snapshot_cache() {
     use indoc::indoc;
 
     let config_str = indoc! {r#"
         backend = "docker"
         image = "18.0"
-
-        [ssl_config]
-        hostname = "postgresql.example.com"
+        snapshot_cache = true
 
         [instances.main]
+
+        [instances.fresh]
+        snapshot_cache = false
     "#};
 
-    assert_eq!(
-        pg_ephemeral::InstanceMap::from([(
-            pg_ephemeral::InstanceName("main".to_string()),
-            pg_ephemeral::Instance {
-                application_name: None,
-                backend: ociman::backend::Selection::Docker,
-                database: pg_client::Database::POSTGRES,
-                seeds: indexmap::IndexMap::new(),
-                ssl_config: Some(pg_ephemeral::definition::SslConfig::Generated {
-                    hostname: "postgresql.example.com".parse().unwrap(),
-                }),
-                superuser: pg_client::User::POSTGRES,
-                image: "18.0".parse().unwrap(),
-                cross_container_access: false,
-                wait_available_timeout: std::time::Duration::from_secs(10),
-            }
-        )]),
-        pg_ephemeral::Config::load_toml(config_str)
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let snapshot_cache = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
             .unwrap()
-            .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+            .snapshot_cache
+    };
+
+    assert!(snapshot_cache("main"));
+    assert!(!snapshot_cache("fresh"));
+}
+
+#[test]
+fn test_config_storage() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+        storage = "tmpfs"
+
+        [instances.main]
+
+        [instances.disk]
+        storage = "disk"
+    "#};
+
+    let instance_map = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let storage = |name: &str| {
+        instance_map
+            .get(&pg_ephemeral::InstanceName(name.to_string()))
             .unwrap()
-    )
+            .storage
+    };
+
+    assert_eq!(storage("main"), pg_ephemeral::Storage::Tmpfs);
+    assert_eq!(storage("disk"), pg_ephemeral::Storage::Disk);
 }
 
 #[tokio::test]
@@ -284,12 +1547,14 @@ fn test_config_seeds_basic() {
             "create-users-table".parse().unwrap(),
             pg_ephemeral::Seed::SqlFile {
                 path: "tests/fixtures/create_users.sql".into(),
+                database: None,
             },
         ),
         (
             "insert-test-data".parse().unwrap(),
             pg_ephemeral::Seed::SqlFile {
                 path: "tests/fixtures/insert_users.sql".into(),
+                database: None,
             },
         ),
     ]
@@ -329,6 +1594,7 @@ fn test_config_seeds_command() {
             "setup-schema".parse().unwrap(),
             pg_ephemeral::Seed::SqlFile {
                 path: "tests/fixtures/schema.sql".into(),
+                database: None,
             },
         ),
         (
@@ -336,6 +1602,7 @@ fn test_config_seeds_command() {
             pg_ephemeral::Seed::Command {
                 command: pg_ephemeral::Command::new("migrate", ["up"]),
                 cache: pg_ephemeral::CommandCacheConfig::CommandHash,
+                database: None,
             },
         ),
     ]
@@ -344,6 +1611,80 @@ fn test_config_seeds_command() {
     assert_eq!(definition.seeds, expected_seeds);
 }
 
+#[test]
+fn test_config_seeds_command_env_and_working_dir() {
+    let toml = indoc::indoc! {r#"
+        backend = "docker"
+        image = "17.1"
+
+        [instances.main.seeds.run-migration]
+        type = "command"
+        command = "bin/rails"
+        arguments = ["db:migrate"]
+        env = { RAILS_ENV = "test", DISABLE_SPRING = "1" }
+        working_dir = "app"
+        cache.type = "command-hash"
+    "#};
+
+    let config = pg_ephemeral::Config::load_toml(toml)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let definition = config
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    let expected_seeds: indexmap::IndexMap<pg_ephemeral::SeedName, pg_ephemeral::Seed> = [(
+        "run-migration".parse().unwrap(),
+        pg_ephemeral::Seed::Command {
+            command: pg_ephemeral::Command::new("bin/rails", ["db:migrate"])
+                .env(
+                    cmd_proc::EnvVariableName::from_static_or_panic("DISABLE_SPRING"),
+                    "1",
+                )
+                .env(
+                    cmd_proc::EnvVariableName::from_static_or_panic("RAILS_ENV"),
+                    "test",
+                )
+                .working_dir("app"),
+            cache: pg_ephemeral::CommandCacheConfig::CommandHash,
+            database: None,
+        },
+    )]
+    .into();
+
+    assert_eq!(definition.seeds, expected_seeds);
+}
+
+#[test]
+fn test_config_seeds_command_invalid_env_name() {
+    let toml = indoc::indoc! {r#"
+        backend = "docker"
+        image = "17.1"
+
+        [instances.main.seeds.run-migration]
+        type = "command"
+        command = "migrate"
+        env = { "RAILS_ENV=test" = "value" }
+        cache.type = "none"
+    "#};
+
+    let error = pg_ephemeral::Config::load_toml(toml)
+        .unwrap_err()
+        .to_string();
+
+    let expected = indoc::indoc! {r#"
+        Decoding as toml failed: TOML parse error at line 4, column 1
+          |
+        4 | [instances.main.seeds.run-migration]
+          | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+        Invalid environment variable name "RAILS_ENV=test": Environment variable name cannot contain '='
+    "#};
+
+    assert_eq!(error, expected);
+}
+
 #[test]
 fn test_config_seeds_script() {
     let toml = indoc::indoc! {r#"
@@ -368,6 +1709,7 @@ fn test_config_seeds_script() {
         "initialize".parse().unwrap(),
         pg_ephemeral::Seed::Script {
             script: "echo 'Starting setup' && psql -c 'CREATE TABLE test (id INT)'".to_string(),
+            database: None,
         },
     )]
     .into();
@@ -375,6 +1717,102 @@ fn test_config_seeds_script() {
     assert_eq!(definition.seeds, expected_seeds);
 }
 
+#[test]
+fn test_config_seeds_sql_directory() {
+    let toml = indoc::indoc! {r#"
+        backend = "docker"
+        image = "17.1"
+
+        [instances.main.seeds.schema]
+        type = "sql-directory"
+        path = "db/schema"
+
+        [instances.main.seeds.data]
+        type = "sql-directory"
+        path = "db/data"
+        pattern = "*.up.sql"
+    "#};
+
+    let config = pg_ephemeral::Config::load_toml(toml)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let definition = config
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    let expected_seeds: indexmap::IndexMap<pg_ephemeral::SeedName, pg_ephemeral::Seed> = [
+        (
+            "schema".parse().unwrap(),
+            pg_ephemeral::Seed::SqlDirectory {
+                path: "db/schema".into(),
+                pattern: None,
+                database: None,
+            },
+        ),
+        (
+            "data".parse().unwrap(),
+            pg_ephemeral::Seed::SqlDirectory {
+                path: "db/data".into(),
+                pattern: Some("*.up.sql".to_string()),
+                database: None,
+            },
+        ),
+    ]
+    .into();
+
+    assert_eq!(definition.seeds, expected_seeds);
+}
+
+#[test]
+fn test_config_seeds_restore() {
+    let toml = indoc::indoc! {r#"
+        backend = "docker"
+        image = "17.1"
+
+        [instances.main.seeds.production-snapshot]
+        type = "restore"
+        path = "tests/fixtures/snapshot.dump"
+
+        [instances.main.seeds.archive]
+        type = "restore"
+        path = "tests/fixtures/archive.tar"
+        format = "tar"
+    "#};
+
+    let config = pg_ephemeral::Config::load_toml(toml)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let definition = config
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    let expected_seeds: indexmap::IndexMap<pg_ephemeral::SeedName, pg_ephemeral::Seed> = [
+        (
+            "production-snapshot".parse().unwrap(),
+            pg_ephemeral::Seed::Restore {
+                path: "tests/fixtures/snapshot.dump".into(),
+                format: pg_ephemeral::RestoreFormat::Custom,
+                database: None,
+            },
+        ),
+        (
+            "archive".parse().unwrap(),
+            pg_ephemeral::Seed::Restore {
+                path: "tests/fixtures/archive.tar".into(),
+                format: pg_ephemeral::RestoreFormat::Tar,
+                database: None,
+            },
+        ),
+    ]
+    .into();
+
+    assert_eq!(definition.seeds, expected_seeds);
+}
+
 #[test]
 fn test_config_seeds_mixed() {
     let toml = indoc::indoc! {r#"
@@ -410,6 +1848,7 @@ fn test_config_seeds_mixed() {
             "schema".parse().unwrap(),
             pg_ephemeral::Seed::SqlFile {
                 path: "tests/fixtures/schema.sql".into(),
+                database: None,
             },
         ),
         (
@@ -417,12 +1856,14 @@ fn test_config_seeds_mixed() {
             pg_ephemeral::Seed::Command {
                 command: pg_ephemeral::Command::new("migrate", ["up", "--verbose"]),
                 cache: pg_ephemeral::CommandCacheConfig::CommandHash,
+                database: None,
             },
         ),
         (
             "verify".parse().unwrap(),
             pg_ephemeral::Seed::Script {
                 script: "psql -c 'SELECT COUNT(*) FROM users'".to_string(),
+                database: None,
             },
         ),
     ]
@@ -431,6 +1872,40 @@ fn test_config_seeds_mixed() {
     assert_eq!(definition.seeds, expected_seeds);
 }
 
+#[test]
+fn test_config_seeds_after() {
+    let toml = indoc::indoc! {r#"
+        backend = "docker"
+        image = "17.1"
+
+        [instances.main.seeds.data]
+        type = "sql-file"
+        path = "tests/fixtures/data.sql"
+        after = ["schema"]
+
+        [instances.main.seeds.schema]
+        type = "sql-file"
+        path = "tests/fixtures/schema.sql"
+    "#};
+
+    let config = pg_ephemeral::Config::load_toml(toml)
+        .unwrap()
+        .instance_map(&pg_ephemeral::config::InstanceDefinition::empty())
+        .unwrap();
+
+    let instance = config
+        .get(&pg_ephemeral::InstanceName("main".to_string()))
+        .unwrap();
+
+    assert_eq!(
+        instance.seed_dependencies,
+        std::collections::BTreeMap::from([(
+            "data".parse().unwrap(),
+            vec!["schema".parse().unwrap()]
+        )])
+    );
+}
+
 #[test]
 fn test_config_seeds_duplicate_name() {
     let toml = indoc::indoc! {r#"
@@ -491,12 +1966,14 @@ fn test_config_seeds_with_git_revision() {
             pg_ephemeral::Seed::SqlFileGitRevision {
                 git_revision: "main".to_string(),
                 path: "tests/fixtures/schema.sql".into(),
+                database: None,
             },
         ),
         (
             "from-filesystem".parse().unwrap(),
             pg_ephemeral::Seed::SqlFile {
                 path: "tests/fixtures/create_users.sql".into(),
+                database: None,
             },
         ),
     ]
@@ -524,17 +2001,7 @@ fn test_config_image_with_sha256_digest() {
     assert_eq!(
         pg_ephemeral::InstanceMap::from([(
             pg_ephemeral::InstanceName("main".to_string()),
-            pg_ephemeral::Instance {
-                application_name: None,
-                backend: ociman::backend::Selection::Docker,
-                database: pg_client::Database::POSTGRES,
-                seeds: indexmap::IndexMap::new(),
-                ssl_config: None,
-                superuser: pg_client::User::POSTGRES,
-                image: expected_image.clone(),
-                cross_container_access: false,
-                wait_available_timeout: std::time::Duration::from_secs(10),
-            }
+            pg_ephemeral::Instance::new(ociman::backend::Selection::Docker, expected_image.clone())
         )]),
         pg_ephemeral::Config::load_toml(config_str)
             .unwrap()
@@ -588,6 +2055,60 @@ fn test_config_invalid_image_format() {
     assert_eq!(error, expected);
 }
 
+#[test]
+fn test_config_invalid_instance_name() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances."a/b"]
+    "#};
+
+    let error = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap_err()
+        .to_string();
+
+    let expected = indoc! {r#"
+        Decoding as toml failed: TOML parse error at line 4, column 12
+          |
+        4 | [instances."a/b"]
+          |            ^^^^^
+        Instance name "a/b" contains '/', only lowercase letters, digits and hyphens are allowed
+    "#};
+
+    assert_eq!(error, expected);
+}
+
+#[test]
+fn test_config_invalid_seed_name() {
+    use indoc::indoc;
+
+    let config_str = indoc! {r#"
+        backend = "docker"
+        image = "18.0"
+
+        [instances.main.seeds."Bad Name"]
+        type = "sql-file"
+        path = "tests/fixtures/create_seed_env_table.sql"
+    "#};
+
+    let error = pg_ephemeral::Config::load_toml(config_str)
+        .unwrap_err()
+        .to_string();
+
+    let expected = indoc! {r#"
+        Decoding as toml failed: TOML parse error at line 4, column 23
+          |
+        4 | [instances.main.seeds."Bad Name"]
+          |                       ^^^^^^^^^^
+        Seed name "Bad Name" contains 'B', only lowercase letters, digits and hyphens are allowed
+    "#};
+
+    assert_eq!(error, expected);
+}
+
 #[test]
 fn test_config_invalid_image_nom_error() {
     use indoc::indoc;
@@ -38,29 +38,30 @@ fn test_cache_status() {
     let expected = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "d66fb32d28eb8d19b4bd03e9460f785ac4e8b1462f355647be54ef7284da4883"
 
         [[seeds]]
         name = "a-schema"
         type = "sql-file"
-        cache_image = "pg-ephemeral/main:6ca66287ad925179b556edbe98c6e813ffd02e1ed129cc4bea99e10f610f656a"
+        cache_image = "pg-ephemeral/main:08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
         status = "miss"
 
         [[seeds]]
         name = "b-data-from-git"
         type = "sql-file-git-revision"
-        cache_image = "pg-ephemeral/main:9d1fe3c033a5353478c44008c8c34a1223ab208664d43a1c63b22172c9d9c645"
+        cache_image = "pg-ephemeral/main:f9824b99086532dd20504c86f28972e00da9017d6e10ec31525e9b8ee082cd33"
         status = "miss"
 
         [[seeds]]
         name = "c-run-command"
         type = "command"
-        cache_image = "pg-ephemeral/main:9c76bdb2e278bff90fa66ea86693deaeab85d2b84ff009d8c31d5d8b0c857e88"
+        cache_image = "pg-ephemeral/main:19195f01e882f219fc1af8bc509c2cab5ffdd629d265f6161b54eb3e7b3d9bd6"
         status = "miss"
 
         [[seeds]]
         name = "d-run-script"
         type = "script"
-        cache_image = "pg-ephemeral/main:2204e587eb3ffecb4d3c372f127cbaf26a3c4df88a63b1fa86ec26036d36ecdc"
+        cache_image = "pg-ephemeral/main:d66fb32d28eb8d19b4bd03e9460f785ac4e8b1462f355647be54ef7284da4883"
         status = "miss"
     "#};
 
@@ -89,11 +90,12 @@ fn test_cache_status_deterministic() {
     let expected = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
 
         [[seeds]]
         name = "schema"
         type = "sql-file"
-        cache_image = "pg-ephemeral/main:6ca66287ad925179b556edbe98c6e813ffd02e1ed129cc4bea99e10f610f656a"
+        cache_image = "pg-ephemeral/main:08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
         status = "miss"
     "#};
 
@@ -122,11 +124,12 @@ fn test_cache_status_change_with_content() {
     let expected_before = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
 
         [[seeds]]
         name = "schema"
         type = "sql-file"
-        cache_image = "pg-ephemeral/main:6ca66287ad925179b556edbe98c6e813ffd02e1ed129cc4bea99e10f610f656a"
+        cache_image = "pg-ephemeral/main:08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
         status = "miss"
     "#};
 
@@ -164,11 +167,12 @@ fn test_cache_status_change_with_image() {
     let expected_before = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
 
         [[seeds]]
         name = "schema"
         type = "sql-file"
-        cache_image = "pg-ephemeral/main:6ca66287ad925179b556edbe98c6e813ffd02e1ed129cc4bea99e10f610f656a"
+        cache_image = "pg-ephemeral/main:08c87cd320f752900298c01cb022f0f001c2fa943ba775b9358ffd381eff7831"
         status = "miss"
     "#};
 
@@ -217,17 +221,18 @@ fn test_cache_status_chain_propagates() {
     let expected_before = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "8f667d9be3933a0aefca5d58acf6541bafe88ba399f23d9ecd8a014369959630"
 
         [[seeds]]
         name = "a-first"
         type = "sql-file"
-        cache_image = "pg-ephemeral/main:f8b223da0e4cf8d8db2add4450845e7563bf9800e27773f3301cdf7740cf6176"
+        cache_image = "pg-ephemeral/main:1e5093bb4f42c78baafce6919b4a7cd212a04914e1f98264513bdc593bf235d1"
         status = "miss"
 
         [[seeds]]
         name = "b-second"
         type = "sql-file"
-        cache_image = "pg-ephemeral/main:96482a6449ac57c34b002ba936158de8ab89819238bd18863c37450d6915cd8e"
+        cache_image = "pg-ephemeral/main:8f667d9be3933a0aefca5d58acf6541bafe88ba399f23d9ecd8a014369959630"
         status = "miss"
     "#};
 
@@ -268,11 +273,12 @@ fn test_cache_status_key_command() {
     let expected_before = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "58e69bd064b616a7add080717304bf2ebe65372062afeefbd70d027638c16fd2"
 
         [[seeds]]
         name = "run-migrations"
         type = "command"
-        cache_image = "pg-ephemeral/main:7f881e9f75f7767a96ff07d2ee9649c0754a228cd69517e31928107abb11b256"
+        cache_image = "pg-ephemeral/main:58e69bd064b616a7add080717304bf2ebe65372062afeefbd70d027638c16fd2"
         status = "miss"
         cache_key_output = "1.0.0"
     "#};
@@ -312,11 +318,12 @@ fn test_cache_status_output_truncation_and_verbose() {
     let expected_truncated = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "0a1ebff51f716df534c163c9038d5cc832bcd248e96336d79be24e0b98ad58c6"
 
         [[seeds]]
         name = "run-migrations"
         type = "command"
-        cache_image = "pg-ephemeral/main:c0bb68b81534ae662400ea38803ee43940c9a96b20c625bbd517dac8018511e8"
+        cache_image = "pg-ephemeral/main:0a1ebff51f716df534c163c9038d5cc832bcd248e96336d79be24e0b98ad58c6"
         status = "miss"
         cache_key_output = "line1 [...2 more lines]"
     "#};
@@ -324,11 +331,12 @@ fn test_cache_status_output_truncation_and_verbose() {
     let expected_verbose = indoc::indoc! {r#"
         version = "0.0.1-pre2"
         image = "17.1"
+        fingerprint = "0a1ebff51f716df534c163c9038d5cc832bcd248e96336d79be24e0b98ad58c6"
 
         [[seeds]]
         name = "run-migrations"
         type = "command"
-        cache_image = "pg-ephemeral/main:c0bb68b81534ae662400ea38803ee43940c9a96b20c625bbd517dac8018511e8"
+        cache_image = "pg-ephemeral/main:0a1ebff51f716df534c163c9038d5cc832bcd248e96336d79be24e0b98ad58c6"
         status = "miss"
         cache_key_output = """
         line1
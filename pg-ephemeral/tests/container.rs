@@ -45,6 +45,7 @@ async fn test_run_container_definition() {
             port: Some(port.into()),
         },
         password: Some(pg_client::Password::from_str(static_password).unwrap()),
+        replication: None,
         ssl_mode: pg_client::SslMode::Disable,
         ssl_root_cert: None,
         user: pg_client::User::from_str(static_user).unwrap(),
@@ -79,7 +80,9 @@ async fn test_run_container_definition() {
         cross_container_access: false,
         application_name: None,
         ssl_config: None,
+        stop_timeout: std::time::Duration::from_secs(10),
         // CI environments may be slow, use 30s instead of default 10s
+        connect_attempt_timeout: None,
         wait_available_timeout: std::time::Duration::from_secs(30),
     };
 
@@ -103,6 +106,99 @@ async fn test_run_container_definition() {
     backend.remove_image_force(&snapshot_image);
 }
 
+#[tokio::test]
+async fn test_stop_graceful_snapshot() {
+    if ociman::testing::platform_not_supported() {
+        return;
+    }
+
+    let backend = ociman::test_backend_setup!();
+    let static_password = "testpass123";
+    let static_user = "postgres";
+    let static_database = "postgres";
+    let stop_timeout = std::time::Duration::from_secs(30);
+    let snapshot_image: ociman::image::Reference =
+        ociman::testing::test_reference("pg-ephemeral-test:graceful-snapshot");
+
+    let mut ociman_container = ociman::Definition::new(
+        backend.clone(),
+        "docker.io/library/postgres:17"
+            .parse::<ociman::image::Reference>()
+            .unwrap(),
+    )
+    .remove_on_drop()
+    .environment_variable(ENV_POSTGRES_PASSWORD, static_password)
+    .environment_variable(ENV_POSTGRES_USER, static_user)
+    .environment_variable(ENV_PGDATA, pg_ephemeral::container::PGDATA)
+    .publish(ociman::Publish::tcp(5432))
+    .run_detached();
+
+    let port = ociman_container.read_host_tcp_port(5432).unwrap();
+
+    let client_config = pg_client::Config {
+        application_name: None,
+        database: pg_client::Database::from_str(static_database).unwrap(),
+        endpoint: pg_client::Endpoint::Network {
+            host: pg_client::Host::IpAddr(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST)),
+            channel_binding: None,
+            host_addr: None,
+            port: Some(port.into()),
+        },
+        password: Some(pg_client::Password::from_str(static_password).unwrap()),
+        replication: None,
+        ssl_mode: pg_client::SslMode::Disable,
+        ssl_root_cert: None,
+        user: pg_client::User::from_str(static_user).unwrap(),
+    };
+
+    wait_for_postgres(&client_config).await;
+
+    // Written without an explicit CHECKPOINT so only the shutdown checkpoint persists it
+    client_config
+        .with_sqlx_connection(async |conn| {
+            sqlx::query("CREATE TABLE graceful_data AS SELECT generate_series(1, 10000) AS id")
+                .execute(&mut *conn)
+                .await
+                .unwrap();
+        })
+        .await
+        .unwrap();
+
+    ociman_container.stop_with_timeout(stop_timeout);
+    ociman_container.commit(&snapshot_image, false).unwrap();
+    drop(ociman_container);
+
+    let definition = pg_ephemeral::container::Definition {
+        image: snapshot_image.clone(),
+        password: pg_client::Password::from_str(static_password).unwrap(),
+        user: pg_client::User::from_str(static_user).unwrap(),
+        database: pg_client::Database::from_str(static_database).unwrap(),
+        backend: backend.clone(),
+        cross_container_access: false,
+        application_name: None,
+        ssl_config: None,
+        stop_timeout,
+        connect_attempt_timeout: None,
+        wait_available_timeout: std::time::Duration::from_secs(30),
+    };
+
+    let mut container = pg_ephemeral::container::Container::run_container_definition(&definition);
+    container.wait_available().await;
+
+    container
+        .with_connection(async |conn| {
+            let row: (i64,) = sqlx::query_as("SELECT count(*) FROM graceful_data")
+                .fetch_one(&mut *conn)
+                .await
+                .unwrap();
+            assert_eq!(row.0, 10000);
+        })
+        .await;
+
+    container.stop_graceful();
+    backend.remove_image_force(&snapshot_image);
+}
+
 async fn wait_for_postgres(config: &pg_client::Config) {
     let sqlx_config = config.to_sqlx_connect_options().unwrap();
 
@@ -151,12 +151,17 @@ fn test_git_revision_seed() {
 
     // Read the JSON output with connection details
     use std::io::BufRead;
-    std::io::BufReader::new(server.stdout().unwrap())
+    let line = std::io::BufReader::new(server.stdout().unwrap())
         .lines()
         .next()
         .unwrap()
         .unwrap();
 
+    let handshake: pg_ephemeral::IntegrationServerHandshake = serde_json::from_str(&line).unwrap();
+
+    assert_eq!(handshake.protocol, pg_ephemeral::cli::Protocol::V0);
+    assert_eq!(handshake.database_url, handshake.config.to_url_string());
+
     // Run psql command to query the data
     let output = cmd_proc::Command::new(pg_ephemeral_bin)
         .arguments([
@@ -184,3 +189,430 @@ fn test_git_revision_seed() {
     drop(server.take_stdin());
     server.wait().unwrap();
 }
+
+#[test]
+fn test_keep_on_failure() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("keep-on-failure");
+
+    dir.write_file(
+        "database.toml",
+        indoc::indoc! {r#"
+            keep_on_failure = true
+
+            [instances.main.seeds.broken]
+            type = "script"
+            script = "exit 1"
+        "#},
+    );
+
+    let pg_ephemeral_bin = env!("CARGO_BIN_EXE_pg-ephemeral");
+
+    let output = cmd_proc::Command::new(pg_ephemeral_bin)
+        .arguments(["run-env", "--", "true"])
+        .working_directory(&dir.path)
+        .output()
+        .unwrap();
+
+    assert!(!output.success(), "failing seed should fail run-env");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    let container_id = stderr
+        .lines()
+        .find_map(|line| line.split_once("Keeping container "))
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .unwrap_or_else(|| panic!("container was not reported, got:\n{stderr}"))
+        .to_string();
+
+    let running = backend
+        .command()
+        .arguments(["container", "inspect", "--format", "{{.State.Running}}"])
+        .argument(&container_id)
+        .stdout()
+        .string();
+
+    backend
+        .command()
+        .arguments(["container", "rm", "--force"])
+        .argument(&container_id)
+        .status()
+        .unwrap();
+
+    assert_eq!(running.unwrap().trim(), "true");
+}
+
+#[test]
+fn test_script_seed_exit_code() {
+    let _backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("script-seed-exit-code");
+
+    dir.write_file(
+        "database.toml",
+        indoc::indoc! {r#"
+            [instances.main.seeds.broken]
+            type = "script"
+            script = "exit 3"
+        "#},
+    );
+
+    let pg_ephemeral_bin = env!("CARGO_BIN_EXE_pg-ephemeral");
+
+    let output = cmd_proc::Command::new(pg_ephemeral_bin)
+        .arguments(["run-env", "--", "true"])
+        .working_directory(&dir.path)
+        .output()
+        .unwrap();
+
+    assert!(!output.success(), "failing seed should fail run-env");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        stderr.contains("Failed to apply seed broken: exited with exit status: 3"),
+        "seed failure was not reported, got:\n{stderr}"
+    );
+}
+
+#[tokio::test]
+async fn test_parallel_seeds() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("parallel-seeds");
+
+    let tables = ["alpha", "beta", "gamma", "delta"];
+
+    let mut definition = common::test_definition(backend)
+        .parallel_seeds(std::num::NonZeroU16::new(2).unwrap())
+        .apply_script(
+            "create-schema".parse().unwrap(),
+            "psql -c 'CREATE SCHEMA seeded'",
+        )
+        .unwrap();
+
+    for table in tables {
+        let file_name = format!("{table}.sql");
+        dir.write_file(
+            &file_name,
+            &format!("CREATE TABLE seeded.{table} (id INTEGER PRIMARY KEY);"),
+        );
+        definition = definition
+            .apply_file(table.parse().unwrap(), dir.path.join(file_name))
+            .unwrap();
+    }
+
+    definition
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let rows = sqlx::query(
+                        "SELECT tablename::text FROM pg_tables WHERE schemaname = 'seeded' ORDER BY tablename",
+                    )
+                    .fetch_all(connection)
+                    .await
+                    .unwrap();
+
+                    let actual: Vec<String> = rows
+                        .iter()
+                        .map(|row| sqlx::Row::get::<String, usize>(row, 0))
+                        .collect();
+
+                    let mut expected: Vec<String> =
+                        tables.iter().map(ToString::to_string).collect();
+                    expected.sort();
+
+                    assert_eq!(expected, actual);
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_parallel_seeds_after() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("parallel-seeds-after");
+
+    dir.write_file(
+        "customers.sql",
+        "CREATE TABLE customers (id INTEGER PRIMARY KEY);",
+    );
+    dir.write_file(
+        "orders.sql",
+        "CREATE TABLE orders (customer_id INTEGER REFERENCES customers);",
+    );
+    dir.write_file(
+        "order-rows.sql",
+        "INSERT INTO customers VALUES (1); INSERT INTO orders VALUES (1);",
+    );
+
+    let definition = common::test_definition(backend)
+        .parallel_seeds(std::num::NonZeroU16::new(4).unwrap())
+        .apply_file("customers".parse().unwrap(), dir.path.join("customers.sql"))
+        .unwrap()
+        .apply_file("orders".parse().unwrap(), dir.path.join("orders.sql"))
+        .unwrap()
+        .apply_file(
+            "order-rows".parse().unwrap(),
+            dir.path.join("order-rows.sql"),
+        )
+        .unwrap()
+        .seed_after("orders".parse().unwrap(), ["customers".parse().unwrap()])
+        .seed_after("order-rows".parse().unwrap(), ["orders".parse().unwrap()]);
+
+    definition
+        .with_container(async |container| {
+            container
+                .with_connection(async |connection| {
+                    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM orders")
+                        .fetch_one(connection)
+                        .await
+                        .unwrap();
+
+                    assert_eq!(count, 1);
+                })
+                .await
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_seed_hooks() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("seed-hooks");
+
+    dir.write_file("create-table.sql", "CREATE TABLE events (id INTEGER);");
+
+    let definition = common::test_definition(backend)
+        .apply_file(
+            "create-table".parse().unwrap(),
+            dir.path.join("create-table.sql"),
+        )
+        .unwrap()
+        .apply_script(
+            "insert-row".parse().unwrap(),
+            "psql -c 'INSERT INTO events VALUES (1)'",
+        )
+        .unwrap();
+
+    let events = std::sync::Mutex::new(Vec::new());
+
+    let hooks = pg_ephemeral::SeedHooks::default()
+        .on_seed_start(|name| events.lock().unwrap().push(format!("start {name}")))
+        .on_seed_end(|name, _elapsed| events.lock().unwrap().push(format!("end {name}")));
+
+    definition
+        .with_container_hooks(&hooks, async |_container| {})
+        .await;
+
+    drop(hooks);
+
+    assert_eq!(
+        events.into_inner().unwrap(),
+        [
+            "start create-table",
+            "end create-table",
+            "start insert-row",
+            "end insert-row",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_seed_hooks_parallel_and_reset() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("seed-hooks-parallel");
+
+    dir.write_file(
+        "alpha.sql",
+        "CREATE TABLE IF NOT EXISTS alpha (id INTEGER);",
+    );
+    dir.write_file("beta.sql", "CREATE TABLE IF NOT EXISTS beta (id INTEGER);");
+
+    // A single connection applies the batch in order, so every seed reports its own span
+    let definition = common::test_definition(backend)
+        .parallel_seeds(std::num::NonZeroU16::new(1).unwrap())
+        .apply_file("alpha".parse().unwrap(), dir.path.join("alpha.sql"))
+        .unwrap()
+        .apply_file("beta".parse().unwrap(), dir.path.join("beta.sql"))
+        .unwrap();
+
+    let events = std::sync::Mutex::new(Vec::new());
+
+    let hooks = pg_ephemeral::SeedHooks::default()
+        .on_seed_start(|name| events.lock().unwrap().push(format!("start {name}")))
+        .on_seed_end(|name, _elapsed| events.lock().unwrap().push(format!("end {name}")));
+
+    definition
+        .with_container_hooks(&hooks, async |container| {
+            container
+                .reset_hooks(
+                    &definition,
+                    pg_ephemeral::ResetStrategy::RecreateDatabase,
+                    &hooks,
+                )
+                .await
+        })
+        .await;
+
+    drop(hooks);
+
+    let expected = ["start alpha", "end alpha", "start beta", "end beta"];
+
+    assert_eq!(events.into_inner().unwrap(), [expected, expected].concat());
+}
+
+#[tokio::test]
+async fn test_reset() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("reset");
+
+    dir.write_file(
+        "items.sql",
+        "CREATE TABLE IF NOT EXISTS items (id INTEGER PRIMARY KEY); INSERT INTO items VALUES (1);",
+    );
+
+    let definition = common::test_definition(backend)
+        .apply_file("items".parse().unwrap(), dir.path.join("items.sql"))
+        .unwrap();
+
+    async fn item_ids(container: &pg_ephemeral::Container) -> Vec<i32> {
+        container
+            .with_connection(async |connection| {
+                sqlx::query_scalar("SELECT id FROM items ORDER BY id")
+                    .fetch_all(connection)
+                    .await
+                    .unwrap()
+            })
+            .await
+    }
+
+    definition
+        .with_container(async |container| {
+            for strategy in [
+                pg_ephemeral::ResetStrategy::Truncate,
+                pg_ephemeral::ResetStrategy::RecreateDatabase,
+            ] {
+                container
+                    .apply_sql("INSERT INTO items VALUES (2), (3)")
+                    .await;
+
+                assert_eq!(item_ids(container).await, [1, 2, 3]);
+
+                container.reset(&definition, strategy).await;
+
+                assert_eq!(item_ids(container).await, [1], "{strategy:?}");
+            }
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_seed_database() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("seed-database");
+    let analytics: pg_client::Database = "analytics".parse().unwrap();
+
+    dir.write_file(
+        "events.sql",
+        "CREATE TABLE events (id INTEGER PRIMARY KEY);",
+    );
+
+    common::test_definition(backend)
+        .additional_database(analytics.clone())
+        .add_seed(
+            "events".parse().unwrap(),
+            pg_ephemeral::Seed::SqlFile {
+                path: dir.path.join("events.sql"),
+                database: Some(analytics.clone()),
+            },
+        )
+        .unwrap()
+        .with_container(async |container| {
+            let query = "SELECT count(*) FROM pg_tables WHERE tablename = 'events'";
+
+            let analytics_config = pg_client::Config {
+                database: analytics.clone(),
+                ..container.client_config().clone()
+            };
+
+            let analytics_count: i64 = analytics_config
+                .with_sqlx_connection(async |connection| {
+                    sqlx::query_scalar(query)
+                        .fetch_one(connection)
+                        .await
+                        .unwrap()
+                })
+                .await
+                .unwrap();
+
+            let default_count: i64 = container
+                .with_connection(async |connection| {
+                    sqlx::query_scalar(query)
+                        .fetch_one(connection)
+                        .await
+                        .unwrap()
+                })
+                .await;
+
+            assert_eq!(analytics_count, 1);
+            assert_eq!(default_count, 0);
+        })
+        .await
+}
+
+#[tokio::test]
+async fn test_snapshot_cache() {
+    let backend = ociman::test_backend_setup!();
+    let dir = common::TestDir::new("snapshot-cache");
+
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+
+    dir.write_file(
+        "runs.sql",
+        &format!("-- {nonce}\nCREATE TABLE runs (id SERIAL PRIMARY KEY);"),
+    );
+
+    let definition = common::test_definition(backend.clone())
+        .snapshot_cache(true)
+        .apply_file("create-table".parse().unwrap(), dir.path.join("runs.sql"))
+        .unwrap()
+        .apply_command(
+            "insert-run".parse().unwrap(),
+            pg_ephemeral::Command::new("psql", ["-c", "INSERT INTO runs DEFAULT VALUES"]),
+            pg_ephemeral::CommandCacheConfig::CommandHash,
+        )
+        .unwrap();
+
+    let fingerprint = definition
+        .load_seeds("main")
+        .unwrap()
+        .fingerprint()
+        .unwrap();
+    let reference: ociman::Reference = format!("pg-ephemeral/main:{fingerprint}").parse().unwrap();
+
+    let count_runs = async |container: &pg_ephemeral::Container| {
+        container
+            .with_connection(async |connection| {
+                let row: (i64,) = sqlx::query_as("SELECT count(*) FROM runs")
+                    .fetch_one(connection)
+                    .await
+                    .unwrap();
+                row.0
+            })
+            .await
+    };
+
+    assert!(!backend.is_image_present(&reference));
+
+    assert_eq!(definition.with_container(count_runs).await, 1);
+
+    assert!(backend.is_image_present(&reference));
+
+    // Seeds applied again would fail on the existing table and insert a second run
+    assert_eq!(definition.with_container(count_runs).await, 1);
+
+    backend.remove_image_force(&reference);
+}
@@ -22,6 +22,53 @@ impl std::fmt::Display for LogicalResourceName {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LogicalResourceNameError {
+    #[error("Logical resource name cannot be empty")]
+    Empty,
+    #[error(
+        "Logical resource name {name:?} contains invalid character {character:?}, only ASCII letters and digits are allowed"
+    )]
+    InvalidCharacter { name: String, character: char },
+}
+
+impl LogicalResourceName {
+    /// Check the name against the CloudFormation logical ID rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty or contains characters outside `[A-Za-z0-9]`.
+    pub fn validate(&self) -> Result<(), LogicalResourceNameError> {
+        if self.0.is_empty() {
+            return Err(LogicalResourceNameError::Empty);
+        }
+
+        match self
+            .0
+            .chars()
+            .find(|character| !character.is_ascii_alphanumeric())
+        {
+            Some(character) => Err(LogicalResourceNameError::InvalidCharacter {
+                name: self.0.clone(),
+                character,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::str::FromStr for LogicalResourceName {
+    type Err = LogicalResourceNameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let name = Self(value.to_string());
+
+        name.validate()?;
+
+        Ok(name)
+    }
+}
+
 pub type ResourceProperties = serde_json::Map<String, serde_json::Value>;
 
 pub trait ToResource {
@@ -80,16 +127,94 @@ impl From<&ParameterKey> for value::ExpString {
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub enum ParameterType {
     String,
+    Number,
+    #[serde(rename = "List<Number>")]
+    NumberList,
+    CommaDelimitedList,
+    #[serde(rename = "AWS::EC2::KeyPair::KeyName")]
+    Ec2KeyPairKeyName,
+    #[serde(rename = "AWS::EC2::SecurityGroup::Id")]
+    Ec2SecurityGroupId,
+    #[serde(rename = "AWS::EC2::Subnet::Id")]
+    Ec2SubnetId,
+    #[serde(rename = "AWS::EC2::VPC::Id")]
+    Ec2VpcId,
+    #[serde(rename = "AWS::SSM::Parameter::Value<String>")]
+    SsmParameterValueString,
 }
 
+/// A template input parameter.
+///
+/// Reference a declared parameter through the [`ParameterKey`] returned by
+/// [`Template::parameter`], which converts into a `Ref` expression.
+///
+/// A `String` parameter with a default:
+///
+/// ```
+/// # use stratosphere_core::template::{Parameter, ParameterType};
+/// let parameter = Parameter {
+///     description: Some("Deployment environment".into()),
+///     r#type: ParameterType::String,
+///     allowed_pattern: None,
+///     allowed_values: Some(vec!["staging".into(), "production".into()]),
+///     default: Some("staging".into()),
+///     no_echo: false,
+/// };
+///
+/// assert_eq!(
+///     parameter.to_cf_value(),
+///     serde_json::json!({
+///         "AllowedValues": ["staging", "production"],
+///         "Default": "staging",
+///         "Description": "Deployment environment",
+///         "Type": "String",
+///     })
+/// );
+/// ```
+///
+/// A `NoEcho` password parameter:
+///
+/// ```
+/// # use stratosphere_core::template::{Parameter, ParameterType};
+/// let parameter = Parameter {
+///     description: None,
+///     r#type: ParameterType::String,
+///     allowed_pattern: None,
+///     allowed_values: None,
+///     default: None,
+///     no_echo: true,
+/// };
+///
+/// assert_eq!(
+///     parameter.to_cf_value(),
+///     serde_json::json!({
+///         "NoEcho": true,
+///         "Type": "String",
+///     })
+/// );
+/// ```
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
 pub struct Parameter {
-    #[serde(rename = "Description")]
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(rename = "Type")]
     pub r#type: ParameterType,
     #[serde(rename = "AllowedPattern", skip_serializing_if = "Option::is_none")]
     pub allowed_pattern: Option<String>,
+    #[serde(rename = "AllowedValues", skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<String>>,
+    #[serde(rename = "Default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(rename = "NoEcho", skip_serializing_if = "std::ops::Not::not")]
+    pub no_echo: bool,
+}
+
+impl Parameter {
+    /// Render the parameter in the shape used under a template's `Parameters` section.
+    #[must_use]
+    pub fn to_cf_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
 }
 
 pub type ParameterKeys = std::collections::BTreeSet<ParameterKey>;
@@ -159,6 +284,54 @@ impl MapName {
 pub type Mapping =
     std::collections::BTreeMap<String, std::collections::BTreeMap<String, serde_json::Value>>;
 
+/// A template output, optionally exported and guarded by a condition.
+///
+/// Without an export:
+///
+/// ```
+/// # use stratosphere_core::template::Output;
+/// # use stratosphere_core::value::ExpString;
+/// let output = Output {
+///     description: "Id of the VPC".into(),
+///     value: ExpString::Ref("Vpc".into()),
+///     export: None,
+///     condition: None,
+/// };
+///
+/// assert_eq!(
+///     output.to_cf_value(),
+///     serde_json::json!({
+///         "Description": "Id of the VPC",
+///         "Value": {"Ref": "Vpc"},
+///     })
+/// );
+/// ```
+///
+/// With an export and a condition:
+///
+/// ```
+/// # use stratosphere_core::template::{Output, OutputExport};
+/// # use stratosphere_core::value::ExpString;
+/// let output = Output {
+///     description: "Id of the VPC".into(),
+///     value: ExpString::Ref("Vpc".into()),
+///     export: Some(OutputExport {
+///         name: "shared-vpc-id".into(),
+///         value: None,
+///     }),
+///     condition: Some("CreateVpc".into()),
+/// };
+///
+/// assert_eq!(
+///     output.to_cf_value(),
+///     serde_json::json!({
+///         "Condition": "CreateVpc",
+///         "Description": "Id of the VPC",
+///         "Export": {"Name": "shared-vpc-id"},
+///         "Value": {"Ref": "Vpc"},
+///     })
+/// );
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct Output {
     #[serde(rename = "Description")]
@@ -167,6 +340,16 @@ pub struct Output {
     pub value: value::ExpString,
     #[serde(rename = "Export", skip_serializing_if = "Option::is_none")]
     pub export: Option<OutputExport>,
+    #[serde(rename = "Condition", skip_serializing_if = "Option::is_none")]
+    pub condition: Option<value::ConditionName>,
+}
+
+impl Output {
+    /// Render the output in the shape used under a template's `Outputs` section.
+    #[must_use]
+    pub fn to_cf_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
@@ -236,6 +419,10 @@ impl Template<'_> {
     ) -> LogicalResourceName {
         let logical_resource_name = logical_resource_name.into();
 
+        if let Err(error) = logical_resource_name.validate() {
+            panic!("{error}")
+        }
+
         let resource = Resource {
             resource_type_identifier: R::RESOURCE_TYPE_NAME,
             resource_properties: resource.to_resource_properties(),
@@ -322,6 +509,7 @@ impl Template<'_> {
                     name: value::join(":", [value::AWS_STACK_NAME, output_key.as_str().into()]),
                     value: None,
                 }),
+                condition: None,
             },
         )
     }
@@ -347,8 +535,130 @@ impl Template<'_> {
         serde_json::to_string(&self).unwrap()
     }
 
+    #[must_use]
+    pub fn to_cf_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+
+    /// Render the template as YAML.
+    ///
+    /// Intrinsic functions are rendered in their JSON object form (`Ref: ...`,
+    /// `Fn::Join: ...`) rather than the `!Ref` style short tags.
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn to_yaml(&self) -> String {
+        serde_yaml::to_string(&json_to_yaml(self.to_cf_value())).unwrap()
+    }
+
     #[must_use]
     pub fn parameter_keys(&self) -> std::collections::BTreeSet<ParameterKey> {
         self.parameters.keys().cloned().collect()
     }
 }
+
+/// Convert explicitly, as with `arbitrary_precision` serde_json numbers do not
+/// serialize as plain numbers through other serializers.
+#[cfg(feature = "yaml")]
+fn json_to_yaml(value: serde_json::Value) -> serde_yaml::Value {
+    match value {
+        serde_json::Value::Null => serde_yaml::Value::Null,
+        serde_json::Value::Bool(value) => serde_yaml::Value::Bool(value),
+        serde_json::Value::Number(number) => {
+            if let Some(value) = number.as_u64() {
+                serde_yaml::Value::Number(value.into())
+            } else if let Some(value) = number.as_i64() {
+                serde_yaml::Value::Number(value.into())
+            } else if let Some(value) = number.as_f64() {
+                serde_yaml::Value::Number(value.into())
+            } else {
+                serde_yaml::Value::String(number.to_string())
+            }
+        }
+        serde_json::Value::String(value) => serde_yaml::Value::String(value),
+        serde_json::Value::Array(values) => {
+            serde_yaml::Value::Sequence(values.into_iter().map(json_to_yaml).collect())
+        }
+        serde_json::Value::Object(map) => serde_yaml::Value::Mapping(
+            map.into_iter()
+                .map(|(key, value)| (serde_yaml::Value::String(key), json_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logical_resource_name_accepts_alphanumeric() {
+        let name: LogicalResourceName = "SecurityGroupA".parse().unwrap();
+
+        assert_eq!(name, LogicalResourceName("SecurityGroupA".to_string()));
+    }
+
+    #[test]
+    fn test_logical_resource_name_rejects_invalid_character() {
+        let error = "security-group-a"
+            .parse::<LogicalResourceName>()
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            LogicalResourceNameError::InvalidCharacter {
+                name: "security-group-a".to_string(),
+                character: '-',
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            r#"Logical resource name "security-group-a" contains invalid character '-', only ASCII letters and digits are allowed"#
+        );
+    }
+
+    #[test]
+    fn test_logical_resource_name_rejects_empty() {
+        assert_eq!(
+            "".parse::<LogicalResourceName>(),
+            Err(LogicalResourceNameError::Empty)
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_roundtrip() {
+        let template = Template::new()
+            .parameter_(
+                "VpcCidr",
+                Parameter {
+                    description: Some("CIDR block for the VPC".into()),
+                    r#type: ParameterType::String,
+                    allowed_pattern: None,
+                    allowed_values: None,
+                    default: Some("10.0.0.0/16".into()),
+                    no_echo: false,
+                },
+            )
+            .output_(
+                "Cidrs",
+                Output {
+                    description: "Subnet CIDR".into(),
+                    value: value::fn_select_string(
+                        0,
+                        value::fn_cidr(value::ExpString::Ref("VpcCidr".into()), 6, 8),
+                    ),
+                    export: None,
+                    condition: None,
+                },
+            );
+
+        let yaml = template.to_yaml();
+
+        assert!(yaml.contains("Ref: VpcCidr"), "{yaml}");
+        assert!(yaml.contains("- 6\n"), "{yaml}");
+
+        let value: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(value, template.to_cf_value());
+    }
+}
@@ -1,7 +1,6 @@
 use crate::template::LogicalResourceName;
 use serde;
 use serde_json;
-use serde_json::json;
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct AttributeName(String);
@@ -446,13 +445,13 @@ pub fn mk_name(suffix: impl Into<ExpString>) -> ExpString {
 
 impl ToValue for &String {
     fn to_value(&self) -> serde_json::Value {
-        ExpString::Literal(self.to_string()).to_value()
+        mk_string(self)
     }
 }
 
 impl ToValue for &LogicalResourceName {
     fn to_value(&self) -> serde_json::Value {
-        serde_json::to_value(&self.0).unwrap()
+        mk_string(&self.0)
     }
 }
 
@@ -561,21 +560,21 @@ impl ToValue for ExpString {
                 second_level_key,
             } => mk_func(
                 "Fn::FindInMap",
-                vec![
-                    serde_json::to_value(map_name).unwrap(),
+                serde_json::Value::Array(vec![
+                    mk_string(map_name.as_str()),
                     top_level_key.to_value(),
-                    serde_json::to_value(second_level_key).unwrap(),
-                ],
+                    mk_string(second_level_key),
+                ]),
             ),
             ExpString::GetAtt {
                 logical_resource_name,
                 attribute_name,
             } => mk_func(
                 "Fn::GetAtt",
-                &[
-                    serde_json::to_value(logical_resource_name).unwrap(),
-                    serde_json::to_value(attribute_name).unwrap(),
-                ],
+                serde_json::Value::Array(vec![
+                    mk_string(&logical_resource_name.0),
+                    mk_string(&attribute_name.0),
+                ]),
             ),
             ExpString::If {
                 condition_name,
@@ -583,36 +582,30 @@ impl ToValue for ExpString {
                 else_branch,
             } => mk_func(
                 "Fn::If",
-                &[
-                    serde_json::to_value(condition_name).unwrap(),
+                serde_json::Value::Array(vec![
+                    mk_string(&condition_name.0),
                     true_branch.to_value(),
                     else_branch.to_value(),
-                ],
+                ]),
             ),
             ExpString::Join { delimiter, values } => mk_func(
                 "Fn::Join",
-                vec![
-                    delimiter.to_value(),
-                    serde_json::to_value(
-                        values
-                            .iter()
-                            .map(|item| item.to_value())
-                            .collect::<Vec<_>>(),
-                    )
-                    .unwrap(),
-                ],
+                serde_json::Value::Array(vec![
+                    mk_string(delimiter),
+                    values.iter().map(ToValue::to_value).collect(),
+                ]),
             ),
-            ExpString::Literal(value) => serde_json::to_value(value).unwrap(),
-            ExpString::Ref(value) => mk_ref(value),
-            ExpString::ImportValue(value) => mk_func("Fn::ImportValue", value),
-            ExpString::Sub { pattern } => mk_func("Fn::Sub", pattern),
+            ExpString::Literal(value) => mk_string(value),
+            ExpString::Ref(value) => mk_ref(&value.0),
+            ExpString::ImportValue(value) => mk_func("Fn::ImportValue", mk_string(&value.0)),
+            ExpString::Sub { pattern } => mk_func("Fn::Sub", mk_string(pattern)),
             ExpString::Select { index, values } => mk_func(
                 "Fn::Select",
-                vec![serde_json::to_value(index).unwrap(), values.to_value()],
+                serde_json::Value::Array(vec![(*index).into(), values.to_value()]),
             ),
             ExpString::Split { delimiter, source } => mk_func(
                 "Fn::Split",
-                vec![serde_json::to_value(delimiter).unwrap(), source.to_value()],
+                serde_json::Value::Array(vec![mk_string(delimiter), source.to_value()]),
             ),
             // Pseudo parameters serialize as Refs
             ExpString::AwsAccountId => mk_ref("AWS::AccountId"),
@@ -684,12 +677,19 @@ impl ToValue for chrono::DateTime<chrono::Utc> {
     }
 }
 
-fn mk_func<T: serde::Serialize>(name: &str, value: T) -> serde_json::Value {
-    json!({name:value})
+/// Wrap an already rendered value in a single key intrinsic function object.
+fn mk_func(name: &str, value: serde_json::Value) -> serde_json::Value {
+    let mut object = serde_json::Map::with_capacity(1);
+    object.insert(name.to_string(), value);
+    serde_json::Value::Object(object)
 }
 
-fn mk_ref<T: serde::Serialize>(value: T) -> serde_json::Value {
-    mk_func("Ref", value)
+fn mk_ref(value: &str) -> serde_json::Value {
+    mk_func("Ref", mk_string(value))
+}
+
+fn mk_string(value: &str) -> serde_json::Value {
+    serde_json::Value::String(value.to_string())
 }
 
 #[derive(Clone, Debug)]
@@ -734,20 +734,14 @@ impl ToValue for ExpStringList {
                 cidr_bits,
             } => mk_func(
                 "Fn::Cidr",
-                vec![
+                serde_json::Value::Array(vec![
                     ip_block.to_value(),
-                    serde_json::to_value(count).unwrap(),
-                    serde_json::to_value(cidr_bits).unwrap(),
-                ],
+                    (*count).into(),
+                    (*cidr_bits).into(),
+                ]),
             ),
             ExpStringList::GetAZs { region } => mk_func("Fn::GetAZs", region.to_value()),
-            ExpStringList::Literal(values) => serde_json::to_value(
-                values
-                    .iter()
-                    .map(|item| item.to_value())
-                    .collect::<Vec<_>>(),
-            )
-            .unwrap(),
+            ExpStringList::Literal(values) => values.iter().map(ToValue::to_value).collect(),
             // Pseudo parameter that returns a list serializes as a Ref
             ExpStringList::AwsNotificationArns => mk_ref("AWS::NotificationARNs"),
         }
@@ -857,14 +851,19 @@ impl ToValue for ExpBool {
     ///
     fn to_value(&self) -> serde_json::Value {
         match self {
-            ExpBool::And(left, right) => mk_func("Fn::And", [left.to_value(), right.to_value()]),
+            ExpBool::And(left, right) => mk_func(
+                "Fn::And",
+                serde_json::Value::Array(vec![left.to_value(), right.to_value()]),
+            ),
             ExpBool::Equals(pair) => match pair {
-                ExpPair::Bool { left, right } => {
-                    mk_func("Fn::Equals", [left.to_value(), right.to_value()])
-                }
-                ExpPair::String { left, right } => {
-                    mk_func("Fn::Equals", [left.to_value(), right.to_value()])
-                }
+                ExpPair::Bool { left, right } => mk_func(
+                    "Fn::Equals",
+                    serde_json::Value::Array(vec![left.to_value(), right.to_value()]),
+                ),
+                ExpPair::String { left, right } => mk_func(
+                    "Fn::Equals",
+                    serde_json::Value::Array(vec![left.to_value(), right.to_value()]),
+                ),
             },
             ExpBool::FindInMap {
                 map_name,
@@ -872,11 +871,11 @@ impl ToValue for ExpBool {
                 second_level_key,
             } => mk_func(
                 "Fn::FindInMap",
-                vec![
-                    serde_json::to_value(map_name).unwrap(),
+                serde_json::Value::Array(vec![
+                    mk_string(map_name.as_str()),
                     top_level_key.to_value(),
-                    serde_json::to_value(second_level_key).unwrap(),
-                ],
+                    mk_string(second_level_key),
+                ]),
             ),
             ExpBool::If {
                 condition_name,
@@ -884,29 +883,25 @@ impl ToValue for ExpBool {
                 else_branch,
             } => mk_func(
                 "Fn::If",
-                [
-                    serde_json::to_value(condition_name).unwrap(),
+                serde_json::Value::Array(vec![
+                    mk_string(&condition_name.0),
                     true_branch.to_value(),
                     else_branch.to_value(),
-                ],
+                ]),
             ),
             ExpBool::Literal(value) => serde_json::Value::Bool(*value),
-            ExpBool::Not(value) => mk_func("Fn::Not", [value.to_value()]),
-            ExpBool::Or(conditions) => mk_func(
-                "Fn::Or",
-                conditions
-                    .iter()
-                    .map(|condition| condition.to_value())
-                    .collect::<Vec<_>>(),
-            ),
+            ExpBool::Not(value) => {
+                mk_func("Fn::Not", serde_json::Value::Array(vec![value.to_value()]))
+            }
+            ExpBool::Or(conditions) => {
+                mk_func("Fn::Or", conditions.iter().map(ToValue::to_value).collect())
+            }
             ExpBool::Select { index, values } => mk_func(
                 "Fn::Select",
-                [
-                    serde_json::Value::Number((*index).into()),
-                    serde_json::Value::Array(
-                        values.iter().map(|v| v.to_value()).collect::<Vec<_>>(),
-                    ),
-                ],
+                serde_json::Value::Array(vec![
+                    (*index).into(),
+                    values.iter().map(ToValue::to_value).collect(),
+                ]),
             ),
         }
     }
@@ -1011,3 +1006,49 @@ pub const AWS_URL_SUFFIX: ExpString = ExpString::AwsUrlSuffix;
 /// assert_eq!(AWS_NOTIFICATION_ARNS.to_value(), json!({"Ref": "AWS::NotificationARNs"}));
 /// ```
 pub const AWS_NOTIFICATION_ARNS: ExpStringList = ExpStringList::AwsNotificationArns;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deeply_nested_join_renders_unchanged() {
+        let depth = 256;
+
+        let mut expression = ExpString::Ref("Root".into());
+        let mut expected = json!({"Ref": "Root"});
+
+        for level in 0..depth {
+            let literal = format!("level-{level}");
+
+            expression = join(
+                ":",
+                [
+                    expression,
+                    literal.as_str().into(),
+                    get_att("Resource", "Arn"),
+                    fn_select_string(0, fn_cidr(AWS_REGION, 2, 8)),
+                ],
+            );
+
+            expected = json!({
+                "Fn::Join": [
+                    ":",
+                    [
+                        expected,
+                        literal,
+                        {"Fn::GetAtt": ["Resource", "Arn"]},
+                        {"Fn::Select": [0, {"Fn::Cidr": [{"Ref": "AWS::Region"}, 2, 8]}]},
+                    ],
+                ]
+            });
+        }
+
+        assert_eq!(expression.to_value(), expected);
+        assert_eq!(
+            serde_json::to_string(&expression).unwrap(),
+            serde_json::to_string(&expected).unwrap()
+        );
+    }
+}
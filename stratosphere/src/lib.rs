@@ -35,6 +35,9 @@ macro_rules! Parameter {
             description: None,
             r#type: $type,
             allowed_pattern: None,
+            allowed_values: None,
+            default: None,
+            no_echo: false,
         }
     };
     (
@@ -46,6 +49,9 @@ macro_rules! Parameter {
             description: Some($description.into()),
             r#type: $type,
             allowed_pattern: None,
+            allowed_values: None,
+            default: None,
+            no_echo: false,
         }
     };
     (
@@ -58,6 +64,9 @@ macro_rules! Parameter {
             description: Some($description.into()),
             r#type: $type,
             allowed_pattern: Some($allowed_pattern.into()),
+            allowed_values: None,
+            default: None,
+            no_echo: false,
         }
     };
 }
@@ -73,6 +82,7 @@ macro_rules! Output {
             description: $description.into(),
             value: $value.into(),
             export: None,
+            condition: None,
         }
     };
     (
@@ -85,6 +95,7 @@ macro_rules! Output {
             description: $description.into(),
             value: $value.into(),
             export: Some($export.into()),
+            condition: None,
         }
     };
 }
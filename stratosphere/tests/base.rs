@@ -73,6 +73,9 @@ fn test_template_explicit() {
                 description: Some("CIDR block for the VPC".into()),
                 r#type: stratosphere::template::ParameterType::String,
                 allowed_pattern: Some(r"^(\d{1,3}\.){3}\d{1,3}/\d{1,2}$".into()),
+                allowed_values: None,
+                default: None,
+                no_echo: false,
             },
         )
         .resource_(
@@ -134,6 +137,7 @@ fn test_template_explicit() {
             stratosphere::template::Output {
                 description: "Id of the security group A".into(),
                 export: None,
+                condition: None,
                 value: stratosphere::value::ExpString::Ref("SecurityGroupA".into()),
             },
         );
@@ -1334,6 +1338,9 @@ fn test_fn_cidr_with_ref() {
                 description: Some("VPC CIDR block".to_string()),
                 r#type: stratosphere::template::ParameterType::String,
                 allowed_pattern: None,
+                allowed_values: None,
+                default: None,
+                no_echo: false,
             },
         );
 
@@ -1456,3 +1463,69 @@ fn test_fn_cidr_multiple_subnets() {
 
     assert_eq!(expected, serde_json::to_value(&template).unwrap());
 }
+
+#[test]
+fn test_parameter_types() {
+    use stratosphere::template::ParameterType;
+
+    let template = Template::build(|template| {
+        let key_name = template.parameter(
+            "KeyName",
+            stratosphere::Parameter! {
+                r#type: ParameterType::Ec2KeyPairKeyName
+            },
+        );
+
+        template.parameter(
+            "InstanceCount",
+            stratosphere::template::Parameter {
+                description: None,
+                r#type: ParameterType::Number,
+                allowed_pattern: None,
+                allowed_values: None,
+                default: Some("1".into()),
+                no_echo: false,
+            },
+        );
+
+        template.parameter(
+            "AmiId",
+            stratosphere::Parameter! {
+                r#type: ParameterType::SsmParameterValueString
+            },
+        );
+
+        template.output(
+            "KeyNameOutput",
+            stratosphere::Output! {
+                description: "Key pair name",
+                value: key_name
+            },
+        );
+    });
+
+    let expected = serde_json::json!({
+        "AWSTemplateFormatVersion": "2010-09-09",
+        "Outputs": {
+            "KeyNameOutput": {
+                "Description": "Key pair name",
+                "Value": {"Ref": "KeyName"}
+            }
+        },
+        "Parameters": {
+            "AmiId": {
+                "Type": "AWS::SSM::Parameter::Value<String>"
+            },
+            "InstanceCount": {
+                "Type": "Number",
+                "Default": "1"
+            },
+            "KeyName": {
+                "Type": "AWS::EC2::KeyPair::KeyName"
+            }
+        },
+        "Resources": {}
+    });
+
+    assert_eq!(expected, serde_json::to_value(&template).unwrap());
+}
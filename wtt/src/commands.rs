@@ -1,11 +1,15 @@
 mod add;
+mod completions;
 mod list;
+mod prune;
 mod remove;
 mod setup;
 mod teardown;
 
 pub use add::Add;
+pub use completions::Completions;
 pub use list::List;
+pub use prune::Prune;
 pub use remove::Remove;
 pub use setup::Setup;
 pub use teardown::Teardown;
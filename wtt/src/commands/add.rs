@@ -1,5 +1,8 @@
+use git_proc::ls_remote::DefaultBranchError;
+
 use crate::{
-    Base, Branch, CommandError, Config, Error, ORIGIN, RepoName, detect_repo_from_cwd, git,
+    Base, Branch, CommandError, Config, Error, ORIGIN_NAME, Remote, RemoteName, RepoName,
+    detect_repo_from_cwd,
 };
 
 #[derive(Debug, clap::Parser)]
@@ -14,6 +17,10 @@ pub struct Add {
     /// Repository name [default: auto-detected from current directory]
     #[clap(long)]
     repo: Option<RepoName>,
+
+    /// Name of the remote to track [default: origin]
+    #[clap(long)]
+    remote: Option<RemoteName>,
 }
 
 impl Add {
@@ -24,6 +31,7 @@ impl Add {
         };
 
         let bare_path = config.bare_repo_path(&repo);
+        let remote_name = self.remote.unwrap_or(ORIGIN_NAME);
 
         if !bare_path.exists() {
             return Err(Error::RepoNotFound(repo));
@@ -42,7 +50,7 @@ impl Add {
             .all()
             .status()?;
 
-        if branch_exists(&bare_path, &self.branch)? {
+        if branch_exists(&bare_path, &remote_name, &self.branch)? {
             log::info!(
                 "Creating worktree for existing branch '{}' at {}",
                 self.branch,
@@ -56,7 +64,7 @@ impl Add {
         } else {
             let base = match self.base {
                 Some(base) => base,
-                None => get_remote_default_branch(&bare_path)?,
+                None => get_remote_default_branch(&bare_path, &remote_name)?,
             };
 
             log::info!(
@@ -73,7 +81,7 @@ impl Add {
                 .status()?;
         }
 
-        set_upstream(&worktree_path, &self.branch)?;
+        set_upstream(&worktree_path, &remote_name, &self.branch)?;
 
         log::info!("Worktree created at {}", worktree_path.display());
 
@@ -81,7 +89,11 @@ impl Add {
     }
 }
 
-fn branch_exists(bare_path: &std::path::Path, branch: &Branch) -> Result<bool, CommandError> {
+fn branch_exists(
+    bare_path: &std::path::Path,
+    remote_name: &RemoteName,
+    branch: &Branch,
+) -> Result<bool, CommandError> {
     let local_result = git_proc::show_ref::new()
         .repo_path(bare_path)
         .verify()
@@ -96,7 +108,7 @@ fn branch_exists(bare_path: &std::path::Path, branch: &Branch) -> Result<bool, C
     let remote_output = git_proc::ls_remote::new()
         .repo_path(bare_path)
         .heads()
-        .remote(&ORIGIN)
+        .remote(&Remote::Name(remote_name.clone()))
         .pattern(branch.as_str())
         .stdout()
         .string()?;
@@ -104,28 +116,34 @@ fn branch_exists(bare_path: &std::path::Path, branch: &Branch) -> Result<bool, C
     Ok(!remote_output.trim().is_empty())
 }
 
-fn get_remote_default_branch(bare_path: &std::path::Path) -> Result<Base, Error> {
-    let output = git_proc::ls_remote::new()
-        .repo_path(bare_path)
-        .symref()
-        .remote(&ORIGIN)
-        .pattern("HEAD")
-        .stdout()
-        .string()?;
-
-    let branch = git::parse_default_branch(&output).map_err(|_| Error::DefaultBranchNotFound)?;
-
-    format!("origin/{branch}")
+fn get_remote_default_branch(
+    bare_path: &std::path::Path,
+    remote_name: &RemoteName,
+) -> Result<Base, Error> {
+    let branch =
+        git_proc::ls_remote::default_branch(&Remote::Name(remote_name.clone()), Some(bare_path))
+            .map_err(|error| match error {
+                DefaultBranchError::Command(error) => Error::Command(error),
+                DefaultBranchError::NoSymref | DefaultBranchError::InvalidBranch(_) => {
+                    Error::DefaultBranchNotFound
+                }
+            })?;
+
+    format!("{remote_name}/{branch}")
         .parse()
         .map_err(|_| Error::DefaultBranchNotFound)
 }
 
-fn set_upstream(worktree_path: &std::path::Path, branch: &Branch) -> Result<(), CommandError> {
-    log::info!("Setting upstream to origin/{branch}");
+fn set_upstream(
+    worktree_path: &std::path::Path,
+    remote_name: &RemoteName,
+    branch: &Branch,
+) -> Result<(), CommandError> {
+    log::info!("Setting upstream to {remote_name}/{branch}");
 
     git_proc::config::new(&format!("branch.{branch}.remote"))
         .repo_path(worktree_path)
-        .value("origin")
+        .value(remote_name.as_str())
         .status()?;
 
     git_proc::config::new(&format!("branch.{branch}.merge"))
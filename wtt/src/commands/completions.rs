@@ -0,0 +1,21 @@
+use crate::Error;
+
+#[derive(Debug, clap::Parser)]
+pub struct Completions {
+    /// Shell to generate completions for
+    shell: clap_complete::Shell,
+}
+
+impl Completions {
+    /// Print the completion script for the requested shell to stdout.
+    pub fn run(self, command: &mut clap::Command) -> Result<(), Error> {
+        self.write(command, &mut std::io::stdout());
+        Ok(())
+    }
+
+    /// Write the completion script for the requested shell to `buffer`.
+    pub fn write(self, command: &mut clap::Command, buffer: &mut dyn std::io::Write) {
+        let name = command.get_name().to_string();
+        clap_complete::generate(self.shell, command, name, buffer);
+    }
+}
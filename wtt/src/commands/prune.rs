@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use crate::{CommandError, Config, Error, RepoName, detect_repo_from_cwd};
+
+#[derive(Debug, clap::Parser)]
+pub struct Prune {
+    /// Repository name [default: auto-detected from current directory]
+    #[clap(long)]
+    repo: Option<RepoName>,
+
+    /// Report stale worktree entries without removing them
+    #[clap(long)]
+    dry_run: bool,
+}
+
+impl Prune {
+    pub fn run(self, config: &Config) -> Result<(), Error> {
+        let repo = match self.repo {
+            Some(repo) => repo,
+            None => detect_repo_from_cwd(config)?,
+        };
+
+        let bare_path = config.bare_repo_path(&repo);
+
+        if !bare_path.exists() {
+            return Err(Error::RepoNotFound(repo));
+        }
+
+        let entries = prune_worktrees(&bare_path, self.dry_run)?;
+
+        if entries.is_empty() {
+            log::info!("No stale worktrees found");
+            return Ok(());
+        }
+
+        for entry in entries {
+            println!("{entry}");
+        }
+
+        Ok(())
+    }
+}
+
+/// Prune stale worktree administrative entries, returning the entries git reported.
+///
+/// With `dry_run` set, entries are only reported and not removed.
+pub fn prune_worktrees(bare_path: &Path, dry_run: bool) -> Result<Vec<String>, CommandError> {
+    let output = git_proc::worktree::prune()
+        .repo_path(bare_path)
+        .dry_run_if(dry_run)
+        .verbose()
+        .stderr()
+        .string()?;
+
+    Ok(output.lines().map(str::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TempDir, bare_repo};
+
+    #[test]
+    fn test_prune_stale_worktree() {
+        let temp_dir = TempDir::new("prune");
+        let bare_path = bare_repo(temp_dir.path());
+        let worktree_path = temp_dir.path().join("stale");
+        let admin_path = bare_path.join("worktrees/stale");
+
+        git_proc::worktree::add(&worktree_path)
+            .repo_path(&bare_path)
+            .new_branch("stale")
+            .status()
+            .unwrap();
+
+        std::fs::remove_dir_all(&worktree_path).unwrap();
+
+        let reported = prune_worktrees(&bare_path, true).unwrap();
+
+        assert_eq!(reported.len(), 1);
+        assert!(reported[0].contains("worktrees/stale"));
+        assert!(admin_path.exists());
+
+        let removed = prune_worktrees(&bare_path, false).unwrap();
+
+        assert_eq!(removed, reported);
+        assert!(!admin_path.exists());
+        assert!(prune_worktrees(&bare_path, true).unwrap().is_empty());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Config, Error, GitUrl, ORIGIN, RepoName};
+use std::path::Path;
+
+use crate::{Config, Error, GitUrl, ORIGIN_NAME, Remote, RemoteName, RepoName};
 
 #[derive(Debug, clap::Parser)]
 pub struct Setup {
@@ -7,12 +9,17 @@ pub struct Setup {
 
     /// Git remote URL to clone
     url: GitUrl,
+
+    /// Name of the remote to clone from [default: origin]
+    #[clap(long)]
+    remote: Option<RemoteName>,
 }
 
 impl Setup {
     pub fn run(self, config: &Config) -> Result<(), Error> {
         let bare_path = config.bare_repo_path(&self.repo);
         let worktree_base = config.worktree_base_path(&self.repo);
+        let remote_name = self.remote.unwrap_or(ORIGIN_NAME);
 
         if bare_path.exists() {
             return Err(Error::RepoAlreadyExists(self.repo));
@@ -20,22 +27,18 @@ impl Setup {
 
         log::info!("Cloning bare repository to {}", bare_path.display());
 
-        git_proc::clone::new(&self.url)
-            .bare()
-            .directory(&bare_path)
-            .status()?;
+        clone_bare(&self.url, &remote_name, &bare_path).status()?;
 
         log::info!("Configuring remote tracking branches");
 
-        git_proc::config::new("remote.origin.fetch")
-            .repo_path(&bare_path)
-            .value("+refs/heads/*:refs/remotes/origin/*")
-            .status()?;
+        let key = fetch_refspec_key(&remote_name);
+        let refspec = fetch_refspec(&remote_name);
 
-        git_proc::fetch::new()
-            .repo_path(&bare_path)
-            .remote(&ORIGIN)
-            .status()?;
+        configure_fetch_refspec(&bare_path, &key, &refspec).status()?;
+
+        let remote = Remote::Name(remote_name);
+
+        fetch_remote(&bare_path, &remote).status()?;
 
         log::info!("Creating worktree directory {}", worktree_base.display());
 
@@ -46,3 +49,124 @@ impl Setup {
         Ok(())
     }
 }
+
+fn clone_bare<'a>(
+    url: &'a GitUrl,
+    remote_name: &'a RemoteName,
+    bare_path: &'a Path,
+) -> git_proc::clone::Clone<'a> {
+    git_proc::clone::new(url)
+        .bare()
+        .origin(remote_name)
+        .directory(bare_path)
+}
+
+fn fetch_refspec_key(remote_name: &RemoteName) -> String {
+    format!("remote.{remote_name}.fetch")
+}
+
+fn fetch_refspec(remote_name: &RemoteName) -> String {
+    format!("+refs/heads/*:refs/remotes/{remote_name}/*")
+}
+
+fn configure_fetch_refspec<'a>(
+    bare_path: &'a Path,
+    key: &'a str,
+    refspec: &'a str,
+) -> git_proc::config::Config<'a> {
+    git_proc::config::new(key)
+        .repo_path(bare_path)
+        .value(refspec)
+}
+
+fn fetch_remote<'a>(bare_path: &'a Path, remote: &'a Remote) -> git_proc::fetch::Fetch<'a> {
+    git_proc::fetch::new().repo_path(bare_path).remote(remote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ORIGIN;
+    use crate::testing::{TempDir, source_repo};
+
+    #[test]
+    fn test_clone_bare_command() {
+        let url: GitUrl = "https://github.com/user/repo.git".parse().unwrap();
+        let bare_path = Path::new("/bare/repo.git");
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("clone")
+            .argument("--bare")
+            .argument("--origin")
+            .argument("origin")
+            .argument("https://github.com/user/repo.git")
+            .argument("/bare/repo.git");
+
+        clone_bare(&url, &ORIGIN_NAME, bare_path).test_eq(&expected);
+    }
+
+    #[test]
+    fn test_configure_fetch_refspec_command() {
+        let bare_path = Path::new("/bare/repo.git");
+        let key = fetch_refspec_key(&ORIGIN_NAME);
+        let refspec = fetch_refspec(&ORIGIN_NAME);
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/bare/repo.git")
+            .argument("config")
+            .argument("remote.origin.fetch")
+            .argument("+refs/heads/*:refs/remotes/origin/*");
+
+        configure_fetch_refspec(bare_path, &key, &refspec).test_eq(&expected);
+    }
+
+    #[test]
+    fn test_fetch_remote_command() {
+        let bare_path = Path::new("/bare/repo.git");
+
+        let expected = cmd_proc::Command::new("git")
+            .argument("-C")
+            .argument("/bare/repo.git")
+            .argument("fetch")
+            .argument("origin");
+
+        fetch_remote(bare_path, &ORIGIN).test_eq(&expected);
+    }
+
+    #[test]
+    fn test_setup_with_remote() {
+        let temp_dir = TempDir::new("setup-remote");
+        let config = Config {
+            bare_clone_dir: temp_dir.path().join("bare"),
+            worktree_dir: temp_dir.path().join("worktrees"),
+        };
+        let repo: RepoName = "my-repo".parse().unwrap();
+
+        Setup {
+            repo: repo.clone(),
+            url: source_repo(temp_dir.path()),
+            remote: Some("upstream".parse().unwrap()),
+        }
+        .run(&config)
+        .unwrap();
+
+        let bare_path = config.bare_repo_path(&repo);
+
+        let refspec = git_proc::config::new("remote.upstream.fetch")
+            .repo_path(&bare_path)
+            .stdout()
+            .string()
+            .unwrap();
+
+        assert_eq!(refspec.trim(), "+refs/heads/*:refs/remotes/upstream/*");
+
+        assert!(
+            git_proc::config::new("remote.origin.url")
+                .repo_path(&bare_path)
+                .stdout()
+                .string()
+                .is_err()
+        );
+    }
+}
@@ -1,7 +1,13 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::Branch;
 
+/// Environment variable pointing at a config file that takes precedence over all others
+pub const ENV_WTT_CONFIG: &str = "WTT_CONFIG";
+
+/// Config file name looked up in the current directory
+pub const LOCAL_CONFIG_FILE_NAME: &str = ".wtt.toml";
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub bare_clone_dir: PathBuf,
@@ -35,37 +41,77 @@ impl Config {
     }
 
     pub fn load(source: &Source) -> Result<Self, Error> {
+        Self::load_with_sources(source).map(|(config, _sources)| config)
+    }
+
+    /// Load the config along with the source that supplied each value
+    ///
+    /// [`Source::Implicit`] layers every config file found, see [`Config::implicit_layers`].
+    pub fn load_with_sources(source: &Source) -> Result<(Self, ValueSources), Error> {
         match source {
-            Source::None => Ok(Self::default()),
-            Source::File(path) => Self::load_file(path),
-            Source::Implicit => Self::load_implicit(),
+            Source::None => Ok((Self::default(), ValueSources::default())),
+            Source::File(path) => Self::load_layers(&[Layer::required(path.clone())]),
+            Source::Implicit => Self::load_layers(&Self::implicit_layers(
+                std::env::var_os(ENV_WTT_CONFIG).map(PathBuf::from),
+                &std::env::current_dir().map_err(|error| Error::CurrentDir(error.kind()))?,
+                std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from),
+            )),
         }
     }
 
-    fn load_file(path: &PathBuf) -> Result<Self, Error> {
-        let contents = std::fs::read_to_string(path).map_err(|error| Error::IO {
-            path: path.clone(),
-            kind: error.kind(),
-        })?;
-        Self::load_toml(&contents)
+    /// Config files consulted for [`Source::Implicit`], highest precedence first
+    ///
+    /// 1. The file named by `$WTT_CONFIG`, which must exist when set
+    /// 2. `.wtt.toml` in the current directory
+    /// 3. `$XDG_CONFIG_HOME/wtt/config.toml`, with `XDG_CONFIG_HOME` defaulting to `~/.config`
+    /// 4. [`Config::default_config_path`]
+    fn implicit_layers(
+        wtt_config: Option<PathBuf>,
+        current_dir: &Path,
+        xdg_config_home: Option<PathBuf>,
+    ) -> Vec<Layer> {
+        let xdg_config_home = xdg_config_home
+            .filter(|path| path.is_absolute())
+            .unwrap_or_else(|| {
+                dirs::home_dir()
+                    .expect("HOME directory not found")
+                    .join(".config")
+            });
+
+        wtt_config
+            .filter(|path| !path.as_os_str().is_empty())
+            .map(Layer::required)
+            .into_iter()
+            .chain([
+                Layer::optional(current_dir.join(LOCAL_CONFIG_FILE_NAME)),
+                Layer::optional(xdg_config_home.join("wtt/config.toml")),
+                Layer::optional(Self::default_config_path()),
+            ])
+            .collect()
     }
 
-    fn load_implicit() -> Result<Self, Error> {
-        let path = Self::default_config_path();
+    /// Merge `layers`, given highest precedence first, over the defaults
+    fn load_layers(layers: &[Layer]) -> Result<(Self, ValueSources), Error> {
+        let mut config = Self::default();
+        let mut sources = ValueSources::default();
+
+        for layer in layers.iter().rev() {
+            let Some(file_config) = layer.read()? else {
+                continue;
+            };
 
-        match std::fs::read_to_string(&path) {
-            Ok(contents) => Self::load_toml(&contents),
-            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
-            Err(error) => Err(Error::IO {
-                path,
-                kind: error.kind(),
-            }),
+            if let Some(bare_clone_dir) = file_config.bare_clone_dir {
+                config.bare_clone_dir = bare_clone_dir;
+                sources.bare_clone_dir = ValueSource::File(layer.path.clone());
+            }
+
+            if let Some(worktree_dir) = file_config.worktree_dir {
+                config.worktree_dir = worktree_dir;
+                sources.worktree_dir = ValueSource::File(layer.path.clone());
+            }
         }
-    }
 
-    fn load_toml(contents: &str) -> Result<Self, Error> {
-        let file_config: FileConfig = toml::from_str(contents)?;
-        Ok(file_config.into())
+        Ok((config, sources))
     }
 
     #[must_use]
@@ -76,6 +122,50 @@ impl Config {
     }
 }
 
+struct Layer {
+    path: PathBuf,
+    required: bool,
+}
+
+impl Layer {
+    fn required(path: PathBuf) -> Self {
+        Self {
+            path,
+            required: true,
+        }
+    }
+
+    fn optional(path: PathBuf) -> Self {
+        Self {
+            path,
+            required: false,
+        }
+    }
+
+    /// Read and decode the file, `None` when an optional file does not exist
+    fn read(&self) -> Result<Option<FileConfig>, Error> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(error) if !self.required && error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(None);
+            }
+            Err(error) => {
+                return Err(Error::IO {
+                    path: self.path.clone(),
+                    kind: error.kind(),
+                });
+            }
+        };
+
+        toml::from_str(&contents)
+            .map(Some)
+            .map_err(|error| Error::TomlDecode {
+                path: self.path.clone(),
+                error,
+            })
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 #[serde(deny_unknown_fields)]
 struct FileConfig {
@@ -83,16 +173,30 @@ struct FileConfig {
     worktree_dir: Option<PathBuf>,
 }
 
-impl From<FileConfig> for Config {
-    fn from(file_config: FileConfig) -> Self {
-        let default = Self::default();
-        Self {
-            bare_clone_dir: file_config.bare_clone_dir.unwrap_or(default.bare_clone_dir),
-            worktree_dir: file_config.worktree_dir.unwrap_or(default.worktree_dir),
+/// Where a config value came from
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ValueSource {
+    #[default]
+    Default,
+    File(PathBuf),
+}
+
+impl std::fmt::Display for ValueSource {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(formatter, "default"),
+            Self::File(path) => write!(formatter, "{}", path.display()),
         }
     }
 }
 
+/// Source of each [`Config`] value
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValueSources {
+    pub bare_clone_dir: ValueSource,
+    pub worktree_dir: ValueSource,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Could not load config file {path}: {kind}")]
@@ -100,8 +204,13 @@ pub enum Error {
         path: PathBuf,
         kind: std::io::ErrorKind,
     },
-    #[error("Decoding as toml failed: {0}")]
-    TomlDecode(#[from] toml::de::Error),
+    #[error("Decoding {path} as toml failed: {error}")]
+    TomlDecode {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    #[error("Could not determine current directory: {0}")]
+    CurrentDir(std::io::ErrorKind),
 }
 
 pub enum Source {
@@ -109,3 +218,107 @@ pub enum Source {
     File(PathBuf),
     Implicit,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn write(path: &Path, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn test_env_file_takes_precedence_over_xdg() {
+        let temp_dir = TempDir::new("config-precedence");
+        let env_path = temp_dir.path().join("env.toml");
+        let xdg_path = temp_dir.path().join("xdg/wtt/config.toml");
+
+        write(&env_path, r#"worktree_dir = "/env/worktrees""#);
+        write(
+            &xdg_path,
+            "bare_clone_dir = \"/xdg/bare\"\nworktree_dir = \"/xdg/worktrees\"\n",
+        );
+
+        let layers = Config::implicit_layers(
+            Some(env_path.clone()),
+            temp_dir.path(),
+            Some(temp_dir.path().join("xdg")),
+        );
+
+        let (config, sources) = Config::load_layers(&layers).unwrap();
+
+        assert_eq!(config.bare_clone_dir, PathBuf::from("/xdg/bare"));
+        assert_eq!(config.worktree_dir, PathBuf::from("/env/worktrees"));
+        assert_eq!(
+            sources,
+            ValueSources {
+                bare_clone_dir: ValueSource::File(xdg_path),
+                worktree_dir: ValueSource::File(env_path),
+            }
+        );
+    }
+
+    #[test]
+    fn test_local_file_takes_precedence_over_xdg() {
+        let temp_dir = TempDir::new("config-local");
+        let local_path = temp_dir.path().join(LOCAL_CONFIG_FILE_NAME);
+        let xdg_path = temp_dir.path().join("xdg/wtt/config.toml");
+
+        write(&local_path, r#"bare_clone_dir = "/local/bare""#);
+        write(&xdg_path, r#"bare_clone_dir = "/xdg/bare""#);
+
+        let layers =
+            Config::implicit_layers(None, temp_dir.path(), Some(temp_dir.path().join("xdg")));
+
+        let (config, sources) = Config::load_layers(&layers).unwrap();
+
+        assert_eq!(config.bare_clone_dir, PathBuf::from("/local/bare"));
+        assert_eq!(sources.bare_clone_dir, ValueSource::File(local_path));
+    }
+
+    #[test]
+    fn test_missing_env_file() {
+        let temp_dir = TempDir::new("config-missing-env");
+        let env_path = temp_dir.path().join("missing.toml");
+
+        let layers = Config::implicit_layers(
+            Some(env_path.clone()),
+            temp_dir.path(),
+            Some(temp_dir.path().join("xdg")),
+        );
+
+        let error = Config::load_layers(&layers).unwrap_err();
+
+        assert!(
+            matches!(&error, Error::IO { path, kind: std::io::ErrorKind::NotFound } if *path == env_path),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let temp_dir = TempDir::new("config-unknown-key");
+        let path = temp_dir.path().join("config.toml");
+
+        write(&path, r#"worktree_directory = "/worktrees""#);
+
+        let error = Config::load_with_sources(&Source::File(path.clone())).unwrap_err();
+
+        let Error::TomlDecode {
+            path: error_path, ..
+        } = &error
+        else {
+            panic!("expected toml decode error, got: {error}");
+        };
+
+        assert_eq!(*error_path, path);
+        assert!(
+            error
+                .to_string()
+                .contains("unknown field `worktree_directory`"),
+            "{error}"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Config, RepoName};
+use crate::{CommandError, Config, Error, GitUrl, RepoName, RepoNameError};
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, thiserror::Error)]
@@ -14,14 +14,56 @@ pub enum DetectError {
 
     #[error("Bare repository not found for '{repo}' at '{}'", .bare_path.display())]
     BareRepoNotFound { repo: RepoName, bare_path: PathBuf },
+
+    #[error("Failed to run git: {0}")]
+    Git(CommandError),
 }
 
+/// Detect the managed repository the current directory belongs to.
+///
+/// Inside a linked worktree or the bare repository itself, the repository is found through
+/// `git rev-parse --git-common-dir`, which points at the shared bare clone. Outside of any
+/// git repository, the repository name is taken from the path below the worktree directory.
 pub fn detect_repo_from_cwd(config: &Config) -> Result<RepoName, DetectError> {
     let cwd = std::env::current_dir().map_err(DetectError::CurrentDir)?;
     detect_repo_from_path(config, &cwd)
 }
 
+/// A repository resolved from a git URL.
+#[derive(Debug)]
+pub struct ResolvedRepo {
+    pub name: RepoName,
+    pub bare_path: PathBuf,
+}
+
+/// Resolve the repository name and bare clone path for a git URL.
+///
+/// Allows operating on a repository without being inside one of its worktrees.
+pub fn resolve_repo_from_url(config: &Config, url: &GitUrl) -> Result<ResolvedRepo, Error> {
+    let resolved = repo_from_url(config, url)?;
+
+    if resolved.bare_path.exists() {
+        Ok(resolved)
+    } else {
+        Err(Error::RepoNotFound(resolved.name))
+    }
+}
+
+fn repo_from_url(config: &Config, url: &GitUrl) -> Result<ResolvedRepo, RepoNameError> {
+    let name = RepoName::from_git_url(url)?;
+    let bare_path = config.bare_repo_path(&name);
+
+    Ok(ResolvedRepo { name, bare_path })
+}
+
 fn detect_repo_from_path(config: &Config, path: &Path) -> Result<RepoName, DetectError> {
+    if let Some(repo_name) = git_common_dir(path)?
+        .as_deref()
+        .and_then(|common_dir| repo_from_common_dir(config, common_dir))
+    {
+        return Ok(repo_name);
+    }
+
     let repo_name =
         extract_repo_name(config, path).ok_or_else(|| DetectError::NotInWorktreeDir {
             path: path.to_path_buf(),
@@ -40,6 +82,40 @@ fn detect_repo_from_path(config: &Config, path: &Path) -> Result<RepoName, Detec
     }
 }
 
+/// The common git directory of the repository containing `path`, `None` outside a repository
+fn git_common_dir(path: &Path) -> Result<Option<PathBuf>, DetectError> {
+    let output = git_proc::rev_parse::new()
+        .repo_path(path)
+        .git_common_dir()
+        .output()
+        .map_err(DetectError::Git)?;
+
+    if !output.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    Ok(Some(path.join(stdout.trim_end_matches('\n'))))
+}
+
+/// Map a common git directory back to the managed bare clone it belongs to
+fn repo_from_common_dir(config: &Config, common_dir: &Path) -> Option<RepoName> {
+    let common_dir = std::fs::canonicalize(common_dir).ok()?;
+    let bare_clone_dir = std::fs::canonicalize(&config.bare_clone_dir).ok()?;
+
+    if common_dir.parent()? != bare_clone_dir {
+        return None;
+    }
+
+    common_dir
+        .file_name()?
+        .to_str()?
+        .strip_suffix(".git")?
+        .parse()
+        .ok()
+}
+
 fn extract_repo_name(config: &Config, path: &Path) -> Option<RepoName> {
     let relative = path.strip_prefix(&config.worktree_dir).ok()?;
     let repo_component = relative.components().next()?;
@@ -50,6 +126,7 @@ fn extract_repo_name(config: &Config, path: &Path) -> Option<RepoName> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{TempDir, source_repo};
     use std::path::PathBuf;
 
     fn test_config() -> Config {
@@ -100,6 +177,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_repo_from_ssh_url() {
+        let config = test_config();
+        let url = "git@github.com:user/my-repo.git".parse().unwrap();
+
+        let resolved = repo_from_url(&config, &url).unwrap();
+
+        assert_eq!(resolved.name.as_str(), "my-repo");
+        assert_eq!(
+            resolved.bare_path,
+            PathBuf::from("/tmp/test-bare/my-repo.git")
+        );
+    }
+
+    #[test]
+    fn test_repo_from_https_url() {
+        let config = test_config();
+        let url = "https://github.com/user/my-repo".parse().unwrap();
+
+        let resolved = repo_from_url(&config, &url).unwrap();
+
+        assert_eq!(resolved.name.as_str(), "my-repo");
+        assert_eq!(
+            resolved.bare_path,
+            PathBuf::from("/tmp/test-bare/my-repo.git")
+        );
+    }
+
+    #[test]
+    fn test_resolve_repo_from_url_not_found() {
+        let config = test_config();
+        let url = "https://github.com/user/missing-repo.git".parse().unwrap();
+
+        assert!(matches!(
+            resolve_repo_from_url(&config, &url),
+            Err(Error::RepoNotFound(repo)) if repo.as_str() == "missing-repo"
+        ));
+    }
+
+    /// Bare clone `my-repo` below a config rooted at `temp_dir`
+    fn managed_repo(temp_dir: &TempDir) -> (Config, PathBuf) {
+        let config = Config {
+            bare_clone_dir: temp_dir.path().join("bare"),
+            worktree_dir: temp_dir.path().join("worktrees"),
+        };
+        let bare_path = config.bare_repo_path(&"my-repo".parse().unwrap());
+
+        git_proc::clone::new(&source_repo(temp_dir.path()))
+            .bare()
+            .directory(&bare_path)
+            .status()
+            .unwrap();
+
+        (config, bare_path)
+    }
+
+    #[test]
+    fn test_detect_from_worktree_subdirectory() {
+        let temp_dir = TempDir::new("detect-worktree");
+        let (config, bare_path) = managed_repo(&temp_dir);
+
+        // Outside the worktree directory, so only the common git directory identifies the repo
+        let worktree_path = temp_dir.path().join("elsewhere/feature");
+
+        git_proc::worktree::add(&worktree_path)
+            .repo_path(&bare_path)
+            .new_branch("feature")
+            .status()
+            .unwrap();
+
+        let subdirectory = worktree_path.join("src");
+        std::fs::create_dir_all(&subdirectory).unwrap();
+
+        let repo = detect_repo_from_path(&config, &subdirectory).unwrap();
+
+        assert_eq!(repo.as_str(), "my-repo");
+    }
+
+    #[test]
+    fn test_detect_from_bare_repo_root() {
+        let temp_dir = TempDir::new("detect-bare");
+        let (config, bare_path) = managed_repo(&temp_dir);
+
+        let repo = detect_repo_from_path(&config, &bare_path).unwrap();
+
+        assert_eq!(repo.as_str(), "my-repo");
+    }
+
+    #[test]
+    fn test_detect_from_unmanaged_repo() {
+        let temp_dir = TempDir::new("detect-unmanaged");
+        let (config, _bare_path) = managed_repo(&temp_dir);
+
+        assert!(matches!(
+            detect_repo_from_path(&config, &temp_dir.path().join("source")),
+            Err(DetectError::NotInWorktreeDir { .. })
+        ));
+    }
+
     #[test]
     fn test_no_match_outside_worktree_dir() {
         let config = test_config();
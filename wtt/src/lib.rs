@@ -6,10 +6,15 @@ mod config;
 mod detect;
 mod git;
 mod repo_name;
+#[cfg(test)]
+mod testing;
 
 pub use base::{Base, BaseError};
-pub use config::{Config, Error as ConfigError, Source as ConfigSource};
-pub use detect::{DetectError, detect_repo_from_cwd};
+pub use config::{
+    Config, ENV_WTT_CONFIG, Error as ConfigError, LOCAL_CONFIG_FILE_NAME, Source as ConfigSource,
+    ValueSource as ConfigValueSource, ValueSources as ConfigValueSources,
+};
+pub use detect::{DetectError, ResolvedRepo, detect_repo_from_cwd, resolve_repo_from_url};
 pub use git_proc::CommandError;
 pub use git_proc::branch::{Branch, BranchError};
 pub use git_proc::url::{GitUrl, GitUrlError, Remote, RemoteName};
@@ -17,7 +22,9 @@ pub use repo_name::{RepoName, RepoNameError};
 
 use std::path::PathBuf;
 
-pub const ORIGIN: Remote = Remote::Name(RemoteName::from_static_or_panic("origin"));
+pub const ORIGIN_NAME: RemoteName = RemoteName::from_static_or_panic("origin");
+
+pub const ORIGIN: Remote = Remote::Name(ORIGIN_NAME);
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -30,6 +37,9 @@ pub enum Error {
     #[error("{0}")]
     Detect(#[from] DetectError),
 
+    #[error("{0}")]
+    RepoName(#[from] RepoNameError),
+
     #[error("Cannot determine default branch from remote")]
     DefaultBranchNotFound,
 
@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use wtt::{Config, ConfigSource, commands};
 
 #[derive(Debug, clap::Parser)]
@@ -22,6 +22,8 @@ enum Command {
     List(commands::List),
     #[clap(visible_alias = "rm")]
     Remove(commands::Remove),
+    Prune(commands::Prune),
+    Completions(commands::Completions),
 }
 
 fn main() {
@@ -37,8 +39,20 @@ fn main() {
         ConfigSource::Implicit
     };
 
-    let config = match Config::load(&config_source) {
-        Ok(config) => config,
+    let config = match Config::load_with_sources(&config_source) {
+        Ok((config, sources)) => {
+            log::debug!(
+                "bare_clone_dir = {} (from {})",
+                config.bare_clone_dir.display(),
+                sources.bare_clone_dir
+            );
+            log::debug!(
+                "worktree_dir = {} (from {})",
+                config.worktree_dir.display(),
+                sources.worktree_dir
+            );
+            config
+        }
         Err(error) => {
             log::error!("{error}");
             std::process::exit(1);
@@ -51,6 +65,8 @@ fn main() {
         Command::Add(cmd) => cmd.run(&config),
         Command::List(cmd) => cmd.run(&config),
         Command::Remove(cmd) => cmd.run(&config),
+        Command::Prune(cmd) => cmd.run(&config),
+        Command::Completions(cmd) => cmd.run(&mut App::command()),
     };
 
     if let Err(error) = result {
@@ -58,3 +74,24 @@ fn main() {
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bash_completions() {
+        let app = App::try_parse_from(["wtt", "completions", "bash"]).unwrap();
+
+        let Command::Completions(completions) = app.command else {
+            panic!("expected completions command");
+        };
+
+        let mut buffer = Vec::new();
+        completions.write(&mut App::command(), &mut buffer);
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("setup"));
+        assert!(output.contains("prune"));
+    }
+}
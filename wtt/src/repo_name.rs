@@ -6,6 +6,30 @@ impl RepoName {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Derive a repository name from the final component of a local path.
+    ///
+    /// A trailing `.git` suffix is stripped, so `/home/me/my-repo.git` yields `my-repo`.
+    pub fn from_path(path: &std::path::Path) -> Result<Self, RepoNameError> {
+        let name = path.file_name().ok_or(RepoNameError::Empty)?;
+        let name = name.to_str().ok_or(RepoNameError::InvalidUtf8)?;
+
+        name.strip_suffix(".git").unwrap_or(name).parse()
+    }
+
+    /// Derive a repository name from the final path component of a git URL.
+    ///
+    /// `git@github.com:user/repo.git` and `https://github.com/user/repo` both yield `repo`.
+    pub fn from_git_url(url: &crate::GitUrl) -> Result<Self, RepoNameError> {
+        use std::path::Path;
+
+        match url {
+            crate::GitUrl::Ssh(url) => Self::from_path(Path::new(url.path())),
+            crate::GitUrl::Https(url) => Self::from_path(Path::new(url.path())),
+            crate::GitUrl::Git(url) => Self::from_path(Path::new(url.path())),
+            crate::GitUrl::Path(url) => Self::from_path(url.path()),
+        }
+    }
 }
 
 impl std::fmt::Display for RepoName {
@@ -39,4 +63,94 @@ pub enum RepoNameError {
     ContainsPathSeparator,
     #[error("Repository name cannot start with a dot")]
     StartsWithDot,
+    #[error("Repository name must be valid UTF-8")]
+    InvalidUtf8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn from_path(path: &str) -> Result<RepoName, RepoNameError> {
+        RepoName::from_path(Path::new(path))
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(from_path("/home/me/my-repo").unwrap().as_str(), "my-repo");
+    }
+
+    #[test]
+    fn test_from_path_strips_git_suffix() {
+        assert_eq!(
+            from_path("/home/me/my-repo.git").unwrap().as_str(),
+            "my-repo"
+        );
+    }
+
+    #[test]
+    fn test_from_path_trailing_slash() {
+        assert_eq!(from_path("/home/me/my-repo/").unwrap().as_str(), "my-repo");
+    }
+
+    fn from_git_url(url: &str) -> Result<RepoName, RepoNameError> {
+        RepoName::from_git_url(&url.parse().unwrap())
+    }
+
+    #[test]
+    fn test_from_git_url_scp() {
+        assert_eq!(
+            from_git_url("git@github.com:user/repo.git")
+                .unwrap()
+                .as_str(),
+            "repo"
+        );
+    }
+
+    #[test]
+    fn test_from_git_url_ssh() {
+        assert_eq!(
+            from_git_url("ssh://git@github.com/user/repo.git")
+                .unwrap()
+                .as_str(),
+            "repo"
+        );
+    }
+
+    #[test]
+    fn test_from_git_url_https() {
+        assert_eq!(
+            from_git_url("https://github.com/user/repo")
+                .unwrap()
+                .as_str(),
+            "repo"
+        );
+    }
+
+    #[test]
+    fn test_from_git_url_path() {
+        assert_eq!(from_git_url("/srv/git/repo.git").unwrap().as_str(), "repo");
+    }
+
+    #[test]
+    fn test_from_path_root() {
+        assert!(matches!(from_path("/"), Err(RepoNameError::Empty)));
+    }
+
+    #[test]
+    fn test_from_path_starts_with_dot() {
+        assert!(matches!(
+            from_path("/home/me/.hidden"),
+            Err(RepoNameError::StartsWithDot)
+        ));
+    }
+
+    #[test]
+    fn test_from_path_only_git_suffix() {
+        assert!(matches!(
+            from_path("/home/me/.git"),
+            Err(RepoNameError::Empty)
+        ));
+    }
 }
@@ -0,0 +1,41 @@
+use std::path::{Path, PathBuf};
+
+pub use git_proc::testing::TempDir;
+use git_proc::testing::commit_as_test_identity;
+
+use crate::GitUrl;
+
+/// Create a non-bare repository with a single empty commit and return its URL.
+pub fn source_repo(directory: &Path) -> GitUrl {
+    let source_path = directory.join("source");
+
+    git_proc::init::new()
+        .directory(&source_path)
+        .status()
+        .unwrap();
+
+    commit_as_test_identity(
+        git_proc::commit::new()
+            .repo_path(&source_path)
+            .message("initial")
+            .allow_empty(),
+    )
+    .status()
+    .unwrap();
+
+    source_path.to_str().unwrap().parse().unwrap()
+}
+
+/// Create a bare clone of a fresh source repository and return its path.
+pub fn bare_repo(directory: &Path) -> PathBuf {
+    let url = source_repo(directory);
+    let bare_path = directory.join("repo.git");
+
+    git_proc::clone::new(&url)
+        .bare()
+        .directory(&bare_path)
+        .status()
+        .unwrap();
+
+    bare_path
+}